@@ -0,0 +1,233 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use serde_json::{json, Value};
+use crate::inputs::Inputs;
+use crate::solution::Day;
+use crate::utils::Error;
+
+// FNV-1a, so the hash of an input doesn't change between Rust versions
+// like the one of std's DefaultHasher might.
+pub fn hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for b in input.as_bytes() {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    format!("{:016x}", hash)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+// Known good answers, keyed by year, day, part and the hash of the input
+// they were computed for.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    entries: Vec<Entry>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(AnswerStore::default()),
+            Err(e) => return Err(Error::io(&format!("Cannot read {}", path.display()), e)),
+        };
+
+        let value: Value = serde_json::from_str(&contents)?;
+        let entries = value
+            .as_array()
+            .ok_or(Error::new("Expected a list of answers"))?
+            .iter()
+            .map(|v| {
+                let field = |name: &str| v.get(name).ok_or_else(|| Error::new(&format!("Answer without {}", name)));
+                let number = |name: &str| field(name)?.as_u64().ok_or_else(|| Error::new(&format!("{} is not a number", name)));
+                let text = |name: &str| field(name)?.as_str().map(|s| s.to_owned()).ok_or_else(|| Error::new(&format!("{} is not a string", name)));
+
+                // Stores written before there were several years only
+                // contain answers for 2021.
+                let year = match v.get("year") {
+                    Some(_) => number("year")? as u16,
+                    None => 2021,
+                };
+
+                Ok(Entry {
+                    year,
+                    day: number("day")? as u8,
+                    part: number("part")? as u8,
+                    input: text("input")?,
+                    answer: text("answer")?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(AnswerStore { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let entries = self.entries
+            .iter()
+            .map(|e| json!({ "year": e.year, "day": e.day, "part": e.part, "input": e.input, "answer": e.answer }))
+            .collect::<Vec<_>>();
+        let contents = serde_json::to_string_pretty(&entries).map_err(Error::from)?;
+
+        fs::write(path, contents + "\n").map_err(|e| Error::io(&format!("Cannot write {}", path.display()), e))
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.year == year && e.day == day && e.part == part && e.input == input)
+            .map(|e| e.answer.as_str())
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, input: &str, answer: &str) {
+        let entry = Entry { year, day, part, input: input.to_owned(), answer: answer.to_owned() };
+        let key = |e: &Entry| (e.year, e.day, e.part, e.input.clone());
+
+        match self.entries.iter_mut().find(|e| key(e) == key(&entry)) {
+            Some(e) => *e = entry,
+            None => {
+                self.entries.push(entry);
+                self.entries.sort_by_key(key);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Status {
+    Pass,
+    Changed { expected: String },
+    Fail(Error),
+    // There is no stored answer for this input yet.
+    Unknown,
+    NoInput,
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+fn verify_day(store: &AnswerStore, day: &Day, inputs: &Inputs) -> Vec<Verification> {
+    let verification = |part, answer, status| Verification { year: day.year, day: day.day, part, answer, status };
+    let input = match inputs.load(day.year, day.day) {
+        Ok(input) => input,
+        Err(_) => {
+            return (1..=2)
+                .map(|part| verification(part, None, Status::NoInput))
+                .collect();
+        }
+    };
+    let input_hash = hash(&input);
+    let generated = day.generate(&input);
+
+    (1..=2)
+        .map(|part| {
+            let answer = generated
+                .as_ref()
+                .map_err(|e| e.clone())
+                .and_then(|g| day.solve(g.as_ref(), part));
+
+            match answer {
+                Ok(answer) => {
+                    let answer = answer.to_string();
+                    let status = match store.expected(day.year, day.day, part, &input_hash) {
+                        Some(expected) if expected == answer => Status::Pass,
+                        Some(expected) => Status::Changed { expected: expected.to_owned() },
+                        None => Status::Unknown,
+                    };
+
+                    verification(part, Some(answer), status)
+                },
+                Err(e) => verification(part, None, Status::Fail(e)),
+            }
+        })
+        .collect()
+}
+
+// Runs all of `days` on their inputs from `inputs` and compares the
+// answers with the store.
+pub fn verify(store: &AnswerStore, inputs: &Inputs, days: &[&Day]) -> Vec<Verification> {
+    days
+        .iter()
+        .flat_map(|d| verify_day(store, d, inputs))
+        .collect()
+}
+
+// Stores the answers of a verification run as the new known good ones.
+pub fn record(store: &mut AnswerStore, inputs: &Inputs, verifications: &[Verification]) -> Result<usize, Error> {
+    let mut recorded = 0;
+
+    for v in verifications {
+        if let Some(answer) = &v.answer {
+            let input = inputs.load(v.year, v.day)?;
+            store.record(v.year, v.day, v.part, &hash(&input), answer);
+            recorded += 1;
+        }
+    }
+
+    Ok(recorded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution;
+
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!("cbf29ce484222325", hash(""));
+        assert_eq!("af63dc4c8601ec8c", hash("a"));
+    }
+
+    #[test]
+    fn record_replaces_existing_answer() {
+        let mut store = AnswerStore::default();
+        store.record(2021, 1, 1, "abc", "7");
+        store.record(2021, 1, 2, "abc", "5");
+        store.record(2021, 1, 1, "abc", "8");
+        store.record(2022, 1, 1, "abc", "9");
+
+        assert_eq!(Some("8"), store.expected(2021, 1, 1, "abc"));
+        assert_eq!(Some("5"), store.expected(2021, 1, 2, "abc"));
+        assert_eq!(Some("9"), store.expected(2022, 1, 1, "abc"));
+        assert_eq!(None, store.expected(2021, 1, 1, "def"));
+    }
+
+    #[test]
+    fn verify_reports_status() -> Result<(), Error> {
+        let root = std::env::temp_dir().join(format!("y2021-verify-{}", std::process::id()));
+        let inputs = Inputs::new(&root, "default");
+        fs::create_dir_all(root.join("2021/day01")).map_err(|e| Error::io("Cannot create directory", e))?;
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        fs::write(inputs.path(2021, 1), input).map_err(|e| Error::io("Cannot write input", e))?;
+
+        let mut store = AnswerStore::default();
+        store.record(2021, 1, 1, &hash(input), "7");
+        store.record(2021, 1, 2, &hash(input), "6");
+
+        let day = |n| solution::day(2021, n).ok_or(Error::new("Day is missing"));
+        let result = verify(&store, &inputs, &[day(1)?]);
+        fs::remove_dir_all(&root).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        assert!(matches!(result[0].status, Status::Pass));
+        assert!(matches!(&result[1].status, Status::Changed { expected } if expected == "6"));
+        assert!(matches!(verify(&store, &inputs, &[day(2)?])[0].status, Status::NoInput));
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+use serde_json::{json, Value};
+use crate::solution::Day;
+use crate::utils::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        Some(Timing {
+            min: *sorted.first()?,
+            median: sorted[sorted.len() / 2],
+            max: *sorted.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Generator,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Generator => write!(f, "generator"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub runs: usize,
+    pub timing: Result<Timing, Error>,
+}
+
+fn measure<T, F>(runs: usize, mut f: F) -> Result<(Timing, T), Error>
+where
+    F: FnMut() -> Result<T, Error>,
+{
+    let mut samples = vec![];
+    let mut last = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(start.elapsed());
+        last = Some(result);
+    }
+
+    let timing = Timing::from_samples(&samples).ok_or(Error::solve("Need at least one run"))?;
+    let last = last.ok_or(Error::solve("Need at least one run"))?;
+    Ok((timing, last))
+}
+
+// Times the generator and both parts of `day` separately, each of them
+// `runs` times.
+pub fn benchmark(day: &Day, input: &str, runs: usize) -> Vec<Measurement> {
+    let generated = measure(runs, || day.generate(input));
+
+    let (timing, input) = match generated {
+        Ok((timing, input)) => (Ok(timing), Some(input)),
+        Err(e) => (Err(e), None),
+    };
+    let mut result = vec![Measurement { year: day.year, day: day.day, stage: Stage::Generator, runs, timing }];
+
+    for (part, stage) in [(1, Stage::Part1), (2, Stage::Part2)] {
+        let timing = match &input {
+            Some(input) => measure(runs, || day.solve(input.as_ref(), part)).map(|(t, _)| t),
+            None => Err(Error::solve("Generator failed")),
+        };
+
+        result.push(Measurement { year: day.year, day: day.day, stage, runs, timing });
+    }
+
+    result
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+
+    if nanos < 10_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 10_000_000 {
+        format!("{}µs", nanos / 1_000)
+    } else {
+        format!("{}ms", nanos / 1_000_000)
+    }
+}
+
+pub fn table(measurements: &[Measurement]) -> String {
+    let mut result = format!("{:>4} {:>3}  {:<10} {:>5} {:>10} {:>10} {:>10}\n", "year", "day", "stage", "runs", "min", "median", "max");
+
+    for m in measurements {
+        let line = match &m.timing {
+            Ok(t) => format!("{:>4} {:>3}  {:<10} {:>5} {:>10} {:>10} {:>10}", m.year, m.day, m.stage.to_string(), m.runs,
+                format_duration(t.min), format_duration(t.median), format_duration(t.max)),
+            Err(e) => format!("{:>4} {:>3}  {:<10} {:>5} failed: {}", m.year, m.day, m.stage.to_string(), m.runs, e),
+        };
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    result
+}
+
+// All durations in CSV and JSON are given in nanoseconds.
+pub fn csv(measurements: &[Measurement]) -> String {
+    let mut result = "year,day,stage,runs,min_ns,median_ns,max_ns,error\n".to_owned();
+
+    for m in measurements {
+        let line = match &m.timing {
+            Ok(t) => format!("{},{},{},{},{},{},{},", m.year, m.day, m.stage, m.runs, t.min.as_nanos(), t.median.as_nanos(), t.max.as_nanos()),
+            Err(e) => format!("{},{},{},{},,,,\"{}\"", m.year, m.day, m.stage, m.runs, e.to_string().replace('"', "\"\"")),
+        };
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    result
+}
+
+pub fn json(measurements: &[Measurement]) -> Value {
+    let entries = measurements
+        .iter()
+        .map(|m| match &m.timing {
+            Ok(t) => json!({
+                "year": m.year,
+                "day": m.day,
+                "stage": m.stage.to_string(),
+                "runs": m.runs,
+                "min_ns": t.min.as_nanos() as u64,
+                "median_ns": t.median.as_nanos() as u64,
+                "max_ns": t.max.as_nanos() as u64,
+            }),
+            Err(e) => json!({
+                "year": m.year,
+                "day": m.day,
+                "stage": m.stage.to_string(),
+                "runs": m.runs,
+                "error": e.to_string(),
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    Value::Array(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution;
+
+    #[test]
+    fn timing_statistics() {
+        let samples = [5, 1, 4, 2, 3].iter().map(|n| Duration::from_millis(*n)).collect::<Vec<_>>();
+        let timing = Timing::from_samples(&samples);
+
+        assert_eq!(Some(Timing {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        }), timing);
+        assert_eq!(None, Timing::from_samples(&[]));
+    }
+
+    #[test]
+    fn benchmark_all_stages() -> Result<(), Error> {
+        let day = solution::day(2021, 1).ok_or(Error::new("Day 1 is missing"))?;
+        let measurements = benchmark(day, "199\n200\n208\n210\n200\n207\n240\n269\n260\n263", 3);
+
+        assert_eq!(vec![Stage::Generator, Stage::Part1, Stage::Part2], measurements.iter().map(|m| m.stage).collect::<Vec<_>>());
+        assert!(measurements.iter().all(|m| m.timing.is_ok()));
+        assert_eq!(4, csv(&measurements).lines().count());
+        Ok(())
+    }
+
+    #[test]
+    fn failing_generator_is_reported() -> Result<(), Error> {
+        let day = solution::day(2021, 1).ok_or(Error::new("Day 1 is missing"))?;
+        let measurements = benchmark(day, "not a number", 3);
+
+        assert!(measurements.iter().all(|m| m.timing.is_err()));
+        Ok(())
+    }
+}
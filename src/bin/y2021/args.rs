@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const SHORT: [(&str, &str); 4] = [
+    ("-y", "--year"),
+    ("-d", "--day"),
+    ("-p", "--part"),
+    ("-i", "--input"),
+];
+
+// Command line flags of a single command. `values` lists the flags that
+// take a value, `switches` the ones that don't.
+#[derive(Debug, Default)]
+pub struct Args {
+    values: HashMap<String, String>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    pub fn parse(args: &[String], values: &[&str], switches: &[&str]) -> Result<Self, String> {
+        let mut result = Args::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let name = SHORT
+                .iter()
+                .find(|(short, _)| short == arg)
+                .map_or(arg.as_str(), |(_, long)| long);
+
+            if values.contains(&name) {
+                let value = iter.next().ok_or(format!("Missing value for {}", name))?;
+                result.values.insert(name.to_owned(), value.to_owned());
+            } else if switches.contains(&name) {
+                result.switches.push(name.to_owned());
+            } else {
+                return Err(format!("Unknown argument {}", arg));
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|v| v.as_str())
+    }
+
+    pub fn parsed<T>(&self, name: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.value(name)
+            .map(|v| v.parse::<T>().map_err(|e| format!("Invalid value for {}: {}", name, e)))
+            .transpose()
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+}
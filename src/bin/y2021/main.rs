@@ -0,0 +1,428 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+use y2021::answers::{self, AnswerStore, Status};
+use y2021::bench;
+use y2021::budget::Budget;
+use y2021::fuzz;
+use y2021::gen::{self, Rng};
+use y2021::inputs::{self, Inputs};
+use y2021::log::{self, Filter};
+use y2021::render;
+use y2021::runner;
+use y2021::scaffold;
+use y2021::solution::{self, Day};
+use y2021::y2021::day01::analyser;
+use y2021::y2021::day02::{self, course};
+use y2021::y2021::day04::{self, Pattern};
+
+mod args;
+
+use args::Args;
+
+const USAGE: &str = "Usage:
+    y2021 [run] [--year <year>] --day <day> --part <1|2> [--input <path|->] [--inputs <dir>] [--profile <name>] [--format <text|json>] [--log <spec>] [--timeout <secs>] [--max-steps <n>]
+    y2021 verify [--year <year>] [--day <day>] [--answers <path>] [--inputs <dir>] [--profile <name>] [--record] [--log <spec>]
+    y2021 bench [--year <year>] [--day <day>] [--runs <n>] [--inputs <dir>] [--profile <name>] [--format <table|csv|json>] [--log <spec>]
+    y2021 all [--year <year>] [--day <day>] [--inputs <dir>] [--profile <name>] [--threads <n>] [--format <table|json>] [--log <spec>] [--timeout <secs>] [--max-steps <n>]
+    y2021 new [--year <year>] --day <day> [--root <dir>]
+    y2021 gen [--year <year>] --day <day> [--size <n>] [--seed <n>]
+    y2021 fuzz [--year <year>] [--day <day>] [--runs <n>] [--seed <n>]
+    y2021 render [--year <year>] --day <day> [--input <path|->] [--inputs <dir>] [--profile <name>] [--steps <n>] [--scale <n>] [--out <dir>] [--log <spec>]
+    y2021 depths [--window <n>] [--input <path|->]
+    y2021 course [--mode <part1|part2|direct|aim>] [--input <path|->]
+    y2021 bingo [--win <rows,columns,diagonals,corners,blackout>] [--input <path|->]
+
+Without --year, run and new use the latest year, the others all years.
+Inputs are read from <inputs>/<year>/day<NN>/<profile>.txt, the profile
+defaults to $AOC_PROFILE or default. Diagnostics are logged to stderr
+with --log or $AOC_LOG, e.g. debug for all days or warn,19=trace.";
+
+const ANSWERS: &str = "answers.json";
+const PROFILE_VAR: &str = "AOC_PROFILE";
+const LOG_VAR: &str = "AOC_LOG";
+
+fn read_input(path: &str) -> Result<String, String> {
+    let mut contents = String::new();
+
+    if path == "-" {
+        io::stdin().read_to_string(&mut contents).map_err(|e| format!("Cannot read stdin: {}", e))?;
+    } else {
+        File::open(path)
+            .and_then(|mut f| f.read_to_string(&mut contents))
+            .map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    }
+
+    Ok(contents)
+}
+
+fn input_set(args: &Args) -> Inputs {
+    let root = Path::new(args.value("--inputs").unwrap_or(inputs::ROOT));
+    let profile = match args.value("--profile") {
+        Some(p) => p.to_owned(),
+        None => env::var(PROFILE_VAR).unwrap_or_else(|_| inputs::DEFAULT_PROFILE.to_owned()),
+    };
+
+    Inputs::new(root, &profile)
+}
+
+fn logging(args: &Args) -> Result<(), String> {
+    let spec = match args.value("--log") {
+        Some(s) => s.to_owned(),
+        None => env::var(LOG_VAR).unwrap_or_default(),
+    };
+
+    log::set_filter(spec.parse::<Filter>().map_err(|e| format!("Invalid --log {}: {}", spec, e))?);
+    Ok(())
+}
+
+// Only with --timeout or --max-steps, otherwise solvers keep to their
+// own limits.
+fn budget(args: &Args) -> Result<Option<Budget>, String> {
+    let time = args.parsed::<f64>("--timeout")?;
+    let steps = args.parsed::<u64>("--max-steps")?;
+
+    if let Some(t) = time.filter(|t| !t.is_finite() || *t < 0.0) {
+        return Err(format!("Invalid --timeout {}", t));
+    }
+
+    Ok(match (time, steps) {
+        (None, None) => None,
+        _ => Some(Budget::new(time.map(Duration::from_secs_f64), steps)),
+    })
+}
+
+// The days picked with --year and --day, out of all years if there is
+// no --year.
+fn selected(args: &Args) -> Result<Vec<&'static Day>, String> {
+    let year = args.parsed::<u16>("--year")?;
+    let only = args.parsed::<u8>("--day")?;
+
+    if let Some(year) = year {
+        solution::year(year).ok_or(format!("There is no year {}", year))?;
+    }
+
+    let days = solution::years()
+        .iter()
+        .filter(|y| year.is_none() || year == Some(y.year))
+        .flat_map(|y| y.days.iter())
+        .filter(|d| only.is_none() || only == Some(d.day))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err(format!("There is no day {}", only.unwrap_or(0)));
+    }
+
+    Ok(days)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--part", "--input", "--inputs", "--profile", "--format", "--log", "--timeout", "--max-steps"], &[])?;
+    logging(&args)?;
+    let year = args.parsed::<u16>("--year")?.unwrap_or(solution::latest().year);
+    let day = args.parsed::<u8>("--day")?.ok_or("Missing --day")?;
+    let part = args.parsed::<u8>("--part")?.ok_or("Missing --part")?;
+    let input = match args.value("--input") {
+        Some(path) => read_input(path)?,
+        None => input_set(&args).load(year, day).map_err(|e| e.to_string())?,
+    };
+
+    if part != 1 && part != 2 {
+        return Err(format!("There is no part {}", part));
+    }
+
+    let day = solution::day(year, day).ok_or(format!("There is no day {} in {}", day, year))?;
+    let budget = budget(&args)?;
+
+    match args.value("--format").unwrap_or("text") {
+        "text" => {
+            let answer = match &budget {
+                Some(b) => day.generate(&input).and_then(|g| day.solve_within(g.as_ref(), part, &mut b.restart())),
+                None => day.run(&input, part),
+            };
+            println!("{}", answer.map_err(|e| e.to_string())?);
+        },
+        "json" => {
+            let report = runner::run_day(day, Ok(input), &[part], budget.as_ref());
+            let failed = report.failures() > 0;
+            println!("{}", serde_json::to_string_pretty(&runner::json(&[report])).map_err(|e| e.to_string())?);
+
+            if failed {
+                return Err(format!("Day {} part {} failed", day.day, part));
+            }
+        },
+        f => return Err(format!("Unknown format {}", f)),
+    }
+
+    Ok(())
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--answers", "--inputs", "--profile", "--log"], &["--record"])?;
+    logging(&args)?;
+    let days = selected(&args)?;
+    let answers = Path::new(args.value("--answers").unwrap_or(ANSWERS));
+    let inputs = input_set(&args);
+
+    let mut store = AnswerStore::load(answers).map_err(|e| e.to_string())?;
+    let verifications = answers::verify(&store, &inputs, &days);
+
+    let mut failures = 0;
+    for v in &verifications {
+        let answer = v.answer.as_deref().unwrap_or("-");
+        let status = match &v.status {
+            Status::Pass => "pass".to_owned(),
+            Status::Unknown => "unknown".to_owned(),
+            Status::NoInput => "no input".to_owned(),
+            Status::Changed { expected } => {
+                failures += 1;
+                format!("changed, expected {}", expected)
+            },
+            Status::Fail(e) => {
+                failures += 1;
+                format!("fail, {}", e)
+            },
+        };
+
+        println!("{} day {:2} part {}: {:<20} {}", v.year, v.day, v.part, answer, status);
+    }
+
+    if args.switch("--record") {
+        let recorded = answers::record(&mut store, &inputs, &verifications).map_err(|e| e.to_string())?;
+        store.save(answers).map_err(|e| e.to_string())?;
+        println!("Recorded {} answers in {}", recorded, answers.display());
+    } else if failures > 0 {
+        return Err(format!("{} answers failed or changed", failures));
+    }
+
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--runs", "--inputs", "--profile", "--format", "--log"], &[])?;
+    logging(&args)?;
+    let days = selected(&args)?;
+    let runs = args.parsed::<usize>("--runs")?.unwrap_or(10);
+    let inputs = input_set(&args);
+
+    if runs == 0 {
+        return Err("Need at least one run".to_owned());
+    }
+
+    let mut measurements = vec![];
+    for day in &days {
+        match inputs.load(day.year, day.day) {
+            Ok(input) => measurements.append(&mut bench::benchmark(day, &input, runs)),
+            Err(e) if days.len() == 1 => return Err(e.to_string()),
+            Err(_) => continue,
+        }
+    }
+
+    match args.value("--format").unwrap_or("table") {
+        "table" => print!("{}", bench::table(&measurements)),
+        "csv" => print!("{}", bench::csv(&measurements)),
+        "json" => println!("{}", serde_json::to_string_pretty(&bench::json(&measurements)).map_err(|e| e.to_string())?),
+        f => return Err(format!("Unknown format {}", f)),
+    }
+
+    Ok(())
+}
+
+fn all(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--inputs", "--profile", "--threads", "--format", "--log", "--timeout", "--max-steps"], &[])?;
+    logging(&args)?;
+    let days = selected(&args)?;
+    let inputs = input_set(&args);
+    let threads = match args.parsed::<usize>("--threads")? {
+        Some(n) => n,
+        None => thread::available_parallelism().map_or(4, |n| n.get()),
+    };
+
+    let start = Instant::now();
+    let reports = runner::run_all(&days, &inputs, threads, budget(&args)?.as_ref());
+
+    match args.value("--format").unwrap_or("table") {
+        "table" => {
+            print!("{}", runner::table(&reports));
+            println!("Finished in {}", bench::format_duration(start.elapsed()));
+        },
+        "json" => println!("{}", serde_json::to_string_pretty(&runner::json(&reports)).map_err(|e| e.to_string())?),
+        f => return Err(format!("Unknown format {}", f)),
+    }
+
+    let failures = reports.iter().map(|r| r.failures()).sum::<usize>();
+    if failures > 0 {
+        return Err(format!("{} parts failed", failures));
+    }
+
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--root"], &[])?;
+    let year = args.parsed::<u16>("--year")?.unwrap_or(solution::latest().year);
+    let day = args.parsed::<u8>("--day")?.ok_or("Missing --day")?;
+    let root = Path::new(args.value("--root").unwrap_or("."));
+
+    let created = scaffold::scaffold(root, year, day).map_err(|e| e.to_string())?;
+    println!("Created {} and {}", created.module.display(), created.fixture.display());
+    Ok(())
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--size", "--seed"], &[])?;
+    let year = args.parsed::<u16>("--year")?.unwrap_or(solution::latest().year);
+    let day = args.parsed::<u8>("--day")?.ok_or("Missing --day")?;
+    let size = args.parsed::<usize>("--size")?.unwrap_or(10);
+    let seed = args.parsed::<u64>("--seed")?.unwrap_or(0);
+
+    let generator = gen::generator(year, day).ok_or(format!("There is no generator for day {} in {}", day, year))?;
+    print!("{}", generator(&mut Rng::new(seed), size));
+    Ok(())
+}
+
+fn fuzz(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--runs", "--seed"], &[])?;
+    let days = selected(&args)?;
+    let runs = args.parsed::<usize>("--runs")?.unwrap_or(10000);
+    let seed = args.parsed::<u64>("--seed")?.unwrap_or(0);
+
+    // Every crash is reported below, the default hook would only repeat it.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut crashes = 0;
+    for day in &days {
+        let found = fuzz::fuzz(day, &mut Rng::new(seed), runs);
+        println!("{} day {:2}: {} crashes in {} runs", day.year, day.day, found.len(), runs);
+
+        for crash in &found {
+            let stage = crash.part.map_or("parser".to_owned(), |p| format!("part {}", p));
+            println!("    {} in the {} on {:?}", crash.message, stage, crash.input);
+        }
+        crashes += found.len();
+    }
+
+    if crashes > 0 {
+        return Err(format!("{} inputs crashed a parser or a part", crashes));
+    }
+
+    Ok(())
+}
+
+fn render(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--year", "--day", "--input", "--inputs", "--profile", "--steps", "--scale", "--out", "--log"], &[])?;
+    logging(&args)?;
+    let year = args.parsed::<u16>("--year")?.unwrap_or(solution::latest().year);
+    let day = args.parsed::<u8>("--day")?.ok_or("Missing --day")?;
+    let steps = args.parsed::<usize>("--steps")?.unwrap_or(100);
+    let scale = args.parsed::<usize>("--scale")?.unwrap_or(4);
+    let out = match args.value("--out") {
+        Some(dir) => Path::new(dir).to_owned(),
+        None => Path::new(render::ROOT).join(year.to_string()).join(format!("day{:02}", day)),
+    };
+
+    let animate = render::animation(year, day).ok_or(format!("Day {} of {} has nothing to render", day, year))?;
+    let input = match args.value("--input") {
+        Some(path) => read_input(path)?,
+        None => input_set(&args).load(year, day).map_err(|e| e.to_string())?,
+    };
+
+    let frames = animate(&input, steps)
+        .map_err(|e| e.in_day(day).to_string())?
+        .iter()
+        .map(|f| f.scaled(scale.max(1)))
+        .collect::<Vec<_>>();
+    let saved = render::save_frames(&out, &format!("day{:02}", day), &frames).map_err(|e| e.to_string())?;
+
+    println!("Wrote {} frames to {}", saved.len(), out.display());
+    Ok(())
+}
+
+// Streams the depths instead of reading them all first, so it copes with
+// inputs of any length.
+fn depths(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--window", "--input"], &[])?;
+    let window = args.parsed::<usize>("--window")?.unwrap_or(1);
+    let path = args.value("--input").unwrap_or("-");
+
+    let analysis = if path == "-" {
+        analyser::analyse_reader(io::stdin().lock(), window)
+    } else {
+        let file = File::open(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+        analyser::analyse_reader(BufReader::new(file), window)
+    }
+    .map_err(|e| e.to_string())?;
+
+    println!("Windows: {}", analysis.windows);
+    println!("Increases: {}", analysis.increases);
+    if let Some(run) = analysis.longest_run {
+        println!("Longest run: {} increases from window {}", run.length, run.start);
+    }
+    if let Some(jump) = analysis.largest_jump {
+        println!("Largest jump: {} at window {}", jump.by, jump.at);
+    }
+    Ok(())
+}
+
+// Writes every position of the submarine as CSV, to plot or check its
+// course with other tools.
+fn course(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--mode", "--input"], &[])?;
+    let rules = args.parsed::<course::Rules>("--mode")?.unwrap_or(course::Rules::new(course::Mode::Aim));
+    let input = read_input(args.value("--input").unwrap_or("-"))?;
+
+    let commands = day02::input_generator(&input).map_err(|e| e.in_day(2).to_string())?;
+    let positions = course::course(&commands, rules).map_err(|e| e.in_day(2).to_string())?;
+
+    println!("step,command,horizontal,lateral,depth,aim,heading");
+    for (i, p) in positions.iter().enumerate() {
+        let command = i.checked_sub(1).map_or(String::new(), |c| commands[c].to_string());
+        println!("{},{},{},{},{},{},{}", i, command, p.horizontal, p.lateral, p.depth, p.aim, p.heading);
+    }
+    Ok(())
+}
+
+fn bingo(args: &[String]) -> Result<(), String> {
+    let args = Args::parse(args, &["--win", "--input"], &[])?;
+    let patterns = match args.value("--win") {
+        Some(win) => win.split(',').map(|p| p.parse::<Pattern>().map_err(|e| format!("Invalid value for --win: {}", e))).collect::<Result<Vec<_>, _>>()?,
+        None => Pattern::PUZZLE.to_vec(),
+    };
+    let input = read_input(args.value("--input").unwrap_or("-"))?;
+
+    let game = day04::input_generator(&input).map_err(|e| e.in_day(4).to_string())?;
+    for win in game.play(&patterns) {
+        println!("Board {} wins on {} with a score of {}", win.board + 1, win.number, win.score);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("all") => all(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("fuzz") => fuzz(&args[1..]),
+        Some("render") => render(&args[1..]),
+        Some("depths") => depths(&args[1..]),
+        Some("course") => course(&args[1..]),
+        Some("bingo") => bingo(&args[1..]),
+        Some("--help") | Some("-h") | None => Err(USAGE.to_owned()),
+        _ => run(&args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,85 @@
+use std::time::{Duration, Instant};
+use crate::utils::Error;
+
+// How long a solver with a loop that might never end may keep going, in
+// time, in steps or both. What a step is depends on the solver, a round
+// of flashes for day 11, a move of the herds for day 25.
+#[derive(Debug, Clone)]
+pub struct Budget {
+    time: Option<Duration>,
+    steps: Option<u64>,
+    started: Instant,
+    used: u64,
+}
+
+impl Budget {
+    pub fn new(time: Option<Duration>, steps: Option<u64>) -> Self {
+        Budget { time, steps, started: Instant::now(), used: 0 }
+    }
+
+    pub fn unlimited() -> Self {
+        Budget::new(None, None)
+    }
+
+    pub fn steps(steps: u64) -> Self {
+        Budget::new(None, Some(steps))
+    }
+
+    pub fn time(time: Duration) -> Self {
+        Budget::new(Some(time), None)
+    }
+
+    // The same limits, counted from now on.
+    pub fn restart(&self) -> Self {
+        Budget::new(self.time, self.steps)
+    }
+
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    // Takes a step out of the budget. Once it is used up this fails, with
+    // `progress` saying how far the solver got.
+    pub fn step<F: FnOnce() -> String>(&mut self, progress: F) -> Result<(), Error> {
+        let elapsed = self.started.elapsed();
+
+        if self.steps.is_some_and(|s| self.used >= s) || self.time.is_some_and(|t| elapsed >= t) {
+            return Err(Error::budget_exhausted(&progress(), self.used, elapsed));
+        }
+
+        self.used += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn steps_run_out() -> Result<(), Error> {
+        let mut budget = Budget::steps(3);
+        for _ in 0..3 {
+            budget.step(|| "counting".to_owned())?;
+        }
+
+        match budget.step(|| "still counting".to_owned()) {
+            Err(Error::BudgetExhausted { steps, what, .. }) => {
+                assert_eq!(3, steps);
+                assert!(what.ends_with(": still counting"));
+            },
+            other => panic!("Expected an exhausted budget, got {:?}", other),
+        }
+
+        let mut restarted = budget.restart();
+        restarted.step(|| String::new())?;
+        Ok(assert_eq!(1, restarted.used()))
+    }
+
+    #[test]
+    fn time_runs_out() {
+        let mut budget = Budget::time(Duration::ZERO);
+        assert!(budget.step(|| String::new()).is_err());
+        assert!(Budget::unlimited().step(|| String::new()).is_ok());
+    }
+}
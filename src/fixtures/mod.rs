@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde_json::Value;
+use crate::solution::Day;
+use crate::utils::Error;
+
+pub const ROOT: &str = "fixtures";
+
+// An example input from a puzzle text, fixtures/2021/day05/sample.txt,
+// and the answers it should give, from the sidecar sample.json:
+//
+// { "part1": 5, "part2": 12 }
+//
+// Parts without an answer are left out, only the generator runs on them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(u8, Value)>,
+}
+
+pub fn dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{:02}", day))
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(&format!("Cannot read {}", path.display()), e))
+}
+
+fn expected(path: &Path) -> Result<Vec<(u8, Value)>, Error> {
+    let value: Value = serde_json::from_str(&read(path)?)?;
+    let answers = value
+        .as_object()
+        .ok_or_else(|| Error::new(&format!("Expected an object of answers in {}", path.display())))?;
+
+    answers
+        .iter()
+        .map(|(key, answer)| match key.as_str() {
+            "part1" => Ok((1, answer.clone())),
+            "part2" => Ok((2, answer.clone())),
+            _ => Err(Error::new(&format!("Unknown part {} in {}", key, path.display()))),
+        })
+        .collect()
+}
+
+// All fixtures of a day, sorted by name. A day without a fixture
+// directory has none.
+pub fn load(root: &Path, year: u16, day: u8) -> Result<Vec<Fixture>, Error> {
+    let dir = dir(root, year, day);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut inputs = fs::read_dir(&dir)
+        .map_err(|e| Error::io(&format!("Cannot read {}", dir.display()), e))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .collect::<Vec<_>>();
+    inputs.sort();
+
+    inputs
+        .iter()
+        .map(|path| {
+            let sidecar = path.with_extension("json");
+            if !sidecar.exists() {
+                return Err(Error::new(&format!("{} has no answers in {}", path.display(), sidecar.display())));
+            }
+
+            Ok(Fixture {
+                name: path.file_stem().map_or(String::new(), |s| s.to_string_lossy().into_owned()),
+                input: read(path)?,
+                expected: expected(&sidecar)?,
+            })
+        })
+        .collect()
+}
+
+// Runs `day` on all of its fixtures and describes every answer that
+// differs from the expected one.
+pub fn check(root: &Path, day: &Day) -> Result<Vec<String>, Error> {
+    let mut mismatches = vec![];
+
+    for fixture in load(root, day.year, day.day)? {
+        let prefix = format!("{} day {} {}", day.year, day.day, fixture.name);
+        let generated = match day.generate(&fixture.input) {
+            Ok(g) => g,
+            Err(e) => {
+                mismatches.push(format!("{}: {}", prefix, e));
+                continue;
+            }
+        };
+
+        for (part, expected) in &fixture.expected {
+            match day.solve(generated.as_ref(), *part) {
+                Ok(answer) if answer.to_json() == *expected => {},
+                Ok(answer) => mismatches.push(format!("{} part {}: expected {}, got {}", prefix, part, expected, answer.to_json())),
+                Err(e) => mismatches.push(format!("{} part {}: {}", prefix, part, e)),
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution;
+
+    #[test]
+    fn all_fixtures() -> Result<(), Error> {
+        let mut mismatches = vec![];
+
+        for year in solution::years() {
+            for day in year.days {
+                mismatches.append(&mut check(Path::new(ROOT), day)?);
+            }
+        }
+
+        assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn mismatches_are_reported() -> Result<(), Error> {
+        let root = std::env::temp_dir().join(format!("y2021-fixtures-{}", std::process::id()));
+        let dir = dir(&root, 2021, 1);
+        let write = |name: &str, contents: &str| fs::write(dir.join(name), contents).map_err(|e| Error::io("Cannot write fixture", e));
+        fs::create_dir_all(&dir).map_err(|e| Error::io("Cannot create directory", e))?;
+        write("a.txt", "1\n2\n3\n")?;
+        write("a.json", "{ \"part1\": 2, \"part2\": 1 }")?;
+        write("b.txt", "1\nx\n")?;
+        write("b.json", "{}")?;
+
+        let day = solution::day(2021, 1).ok_or(Error::new("Day 1 is missing"))?;
+        let result = check(&root, day);
+        write("c.txt", "")?;
+        let missing = load(&root, 2021, 1);
+        fs::remove_dir_all(&root).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        let result = result?;
+        assert_eq!("2021 day 1 a part 2: expected 1, got 0", result[0]);
+        assert!(result[1].starts_with("2021 day 1 b: day 1, line 2"));
+        assert_eq!(2, result.len());
+        assert!(missing.is_err());
+        Ok(())
+    }
+}
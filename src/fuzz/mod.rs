@@ -0,0 +1,208 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use crate::fixtures;
+use crate::gen::{self, Rng};
+use crate::solution::{self, Day};
+
+// Bits of puzzle inputs that parsers tend to trip over.
+const TOKENS: &[&str] = &[
+    "0", "1", "-1", "9", "-", ",", "..", " ", "\n", "\n\n", "|", "->", "=", "[", "]", "#", ".",
+    "x=", "y=", "fold along ", "on ", "off ", "--- scanner ", "4294967296", "99999999999999999999",
+];
+
+// Longer than this on a single broken input is as good as hanging.
+pub const SLOW: Duration = Duration::from_secs(5);
+
+// An input that made a day panic or take longer than SLOW, what it said
+// and in which part, or None if it was the generator.
+#[derive(Debug, Clone)]
+pub struct Crash {
+    pub input: String,
+    pub part: Option<u8>,
+    pub message: String,
+}
+
+// What a fuzzing run starts from: the examples, a small generated input
+// and nothing at all. Most mutations of these are still close enough to
+// a real input to get deep into the parser.
+fn corpus(day: &Day, rng: &mut Rng) -> Vec<String> {
+    let mut corpus = fixtures::load(Path::new(fixtures::ROOT), day.year, day.day)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|f| f.input)
+        .collect::<Vec<_>>();
+
+    if let Some(generate) = gen::generator(day.year, day.day) {
+        corpus.push(generate(rng, 1));
+    }
+
+    corpus.push(String::new());
+    corpus
+}
+
+fn random_bytes(rng: &mut Rng) -> Vec<u8> {
+    (0..rng.below(64)).map(|_| rng.next_u64() as u8).collect()
+}
+
+fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+
+    for _ in 0..1 + rng.below(4) {
+        let at = rng.below(bytes.len() + 1);
+        let end = (at + rng.below(16)).min(bytes.len());
+
+        match rng.below(6) {
+            0 => bytes.truncate(at),
+            1 => { bytes.drain(at..end); },
+            2 => { bytes.splice(at..at, rng.pick(TOKENS).bytes()); },
+            3 => { bytes.splice(at..end, rng.pick(TOKENS).bytes()); },
+            4 => { bytes.splice(at..at, bytes[at..end].to_vec()); },
+            _ => { bytes.splice(at..at, random_bytes(rng)); },
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// Feeds `runs` broken inputs to the generator of `day`. It may turn them
+// down or accept them, but it must never panic or get stuck, and neither
+// may the parts solving the inputs it accepts.
+pub fn fuzz(day: &Day, rng: &mut Rng, runs: usize) -> Vec<Crash> {
+    let corpus = corpus(day, rng);
+    let mut crashes = vec![];
+
+    for run in 0..runs {
+        let input = match run % 8 {
+            0 => String::from_utf8_lossy(&random_bytes(rng)).into_owned(),
+            _ => {
+                let base = rng.pick(&corpus);
+                mutate(rng, base)
+            },
+        };
+
+        // Artefacts like the letters of day 13 would only get in the way.
+        let mut part = None;
+        let mut slow = None;
+        let (result, _) = solution::collect_artefacts(|| panic::catch_unwind(AssertUnwindSafe(|| {
+            let started = Instant::now();
+            let generated = day.generate(&input);
+            if started.elapsed() > SLOW {
+                slow = Some((None, started.elapsed()));
+            }
+
+            if let Ok(generated) = generated {
+                for p in 1..=2 {
+                    part = Some(p);
+                    let started = Instant::now();
+                    let _ = day.solve(generated.as_ref(), p);
+                    if slow.is_none() && started.elapsed() > SLOW {
+                        slow = Some((part, started.elapsed()));
+                    }
+                }
+            }
+        })));
+
+        match (result, slow) {
+            (Err(payload), _) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+
+                crashes.push(Crash { input, part, message });
+            },
+            (Ok(()), Some((part, took))) => crashes.push(Crash { input, part, message: format!("Took {:.1?}", took) }),
+            (Ok(()), None) => {},
+        }
+    }
+
+    crashes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{Answer, Solution};
+    use crate::utils::Error;
+    use std::sync::mpsc;
+    use std::thread;
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input.lines().next().map_or(0, |l| l.as_bytes()[1] as usize))
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, Error> {
+            Ok((*input).into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, Error> {
+            Ok((100 / (*input % 2)).into())
+        }
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let day = Day { year: 2021, day: 1, solution: &Fragile };
+        let crashes = fuzz(&day, &mut Rng::new(1), 200);
+
+        assert!(crashes.iter().any(|c| c.part.is_none() && c.message.contains("out of bounds")));
+        assert!(crashes.iter().any(|c| c.part == Some(2) && c.message.contains("divide by zero")));
+        assert!(crashes.iter().all(|c| c.part != Some(1)));
+    }
+
+    // Each day runs on its own thread, so one that gets stuck fails the
+    // test after DEADLINE instead of stalling it.
+    #[test]
+    fn days_never_panic() {
+        const SEEDS: u64 = 4;
+        const RUNS: usize = 1000;
+        const DEADLINE: Duration = Duration::from_secs(120);
+
+        let days = solution::years().iter().flat_map(|y| y.days.iter()).collect::<Vec<_>>();
+        let (sender, receiver) = mpsc::channel();
+
+        for &day in &days {
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let found = (0..SEEDS)
+                    .flat_map(|seed| fuzz(day, &mut Rng::new(day.day as u64 * 1000 + seed), RUNS))
+                    .collect::<Vec<_>>();
+                let _ = sender.send((day, found));
+            });
+        }
+
+        let deadline = Instant::now() + DEADLINE;
+        let mut finished = vec![];
+        let mut crashes = vec![];
+
+        while finished.len() < days.len() {
+            let Ok((day, found)) = receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) else {
+                break;
+            };
+
+            finished.push((day.year, day.day));
+            for crash in found {
+                crashes.push(format!("{} day {} part {:?}: {} on {:?}", day.year, day.day, crash.part, crash.message, crash.input));
+            }
+        }
+        for day in days.iter().filter(|d| !finished.contains(&(d.year, d.day))) {
+            crashes.push(format!("{} day {}: still running after {:?}", day.year, day.day, DEADLINE));
+        }
+
+        assert!(crashes.is_empty(), "\n{}", crashes.join("\n"));
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        for day in solution::years().iter().flat_map(|y| y.days.iter()) {
+            assert!(day.generate("\u{0}garbage\u{fffd}").is_err(), "{} day {}", day.year, day.day);
+        }
+    }
+}
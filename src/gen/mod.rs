@@ -0,0 +1,111 @@
+use std::ops::RangeInclusive;
+
+pub mod y2021;
+
+// SplitMix64, small and good enough to come up with puzzle inputs. The
+// same seed always gives the same inputs, so a failing seed can be rerun.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in 0..n, n must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let size = (range.end() - range.start()) as usize + 1;
+        range.start() + self.below(size) as i64
+    }
+
+    // True in `percent` out of 100 cases.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// Writes a random but valid puzzle input. What `size` means depends on
+// the day, the number of lines, boards, scanners and so on, but a bigger
+// size always gives a bigger input.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    match year {
+        2021 => y2021::generator(day),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+    use crate::solution;
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+
+        assert_eq!((0..10).map(|_| a.next_u64()).collect::<Vec<_>>(), (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert!((0..1000).all(|_| (-3..=3).contains(&a.range(-3..=3))));
+    }
+
+    // Every generated input has to be accepted by the day's generator and
+    // solved by both parts.
+    #[test]
+    fn generated_inputs_are_solved() {
+        let mut failures = vec![];
+
+        for day in solution::years().iter().flat_map(|y| y.days.iter()) {
+            let generate = match generator(day.year, day.day) {
+                Some(g) => g,
+                None => continue,
+            };
+
+            for seed in 0..4 {
+                let input = generate(&mut Rng::new(seed), 1 + seed as usize);
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let generated = day.generate(&input)?;
+                    for part in 1..=2 {
+                        day.solve(generated.as_ref(), part)?;
+                    }
+                    Ok::<(), crate::utils::Error>(())
+                }));
+
+                match result {
+                    Ok(Ok(())) => {},
+                    Ok(Err(e)) => failures.push(format!("{} day {} seed {}: {}", day.year, day.day, seed, e)),
+                    Err(_) => failures.push(format!("{} day {} seed {}: panicked", day.year, day.day, seed)),
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
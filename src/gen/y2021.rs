@@ -0,0 +1,618 @@
+use std::collections::HashSet;
+use crate::gen::{Generator, Rng};
+use crate::utils::Point3;
+use crate::y2021::{day11, day24, day25};
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        9 => day09,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        15 => day15,
+        16 => day16,
+        17 => day17,
+        18 => day18,
+        19 => day19,
+        20 => day20,
+        21 => day21,
+        22 => day22,
+        23 => day23,
+        24 => day24,
+        25 => day25,
+        _ => return None,
+    };
+
+    Some(generator)
+}
+
+fn lines<I: IntoIterator<Item = String>>(lines: I) -> String {
+    lines.into_iter().map(|l| l + "\n").collect()
+}
+
+fn digits(rng: &mut Rng, width: usize, height: usize, digit: fn(&mut Rng) -> u8) -> String {
+    lines((0..height).map(|_| (0..width).map(|_| (b'0' + digit(rng)) as char).collect::<String>()))
+}
+
+fn joined<T: ToString>(items: &[T], separator: &str) -> String {
+    items.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(separator)
+}
+
+// Depths taking a random walk, with the occasional repeated depth.
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..=200);
+
+    lines((0..10 * size).map(|_| {
+        depth = (depth + rng.range(-20..=30)).max(0);
+        depth.to_string()
+    }))
+}
+
+// The submarine never goes above the surface.
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+
+    lines((0..10 * size).map(|_| {
+        let n = rng.range(1..=20);
+        let command = match rng.below(3) {
+            0 => "forward",
+            1 if depth >= n => "up",
+            _ => "down",
+        };
+
+        match command {
+            "up" => depth -= n,
+            "down" => depth += n,
+            _ => {},
+        }
+        format!("{} {}", command, n)
+    }))
+}
+
+// Every other reading may be followed by its complement, so that there
+// are as many ones as zeros in some of the positions.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    let width = 5 + rng.below(8);
+    let mut readings = vec![];
+
+    while readings.len() < 4 * size + 2 {
+        let reading = (0..width).map(|_| *rng.pick(&['0', '1'])).collect::<String>();
+        if rng.chance(30) {
+            readings.push(reading.chars().map(|c| if c == '0' { '1' } else { '0' }).collect());
+        }
+        readings.push(reading);
+    }
+
+    lines(readings)
+}
+
+// `size` boards, mostly 5x5 but also smaller and larger ones, and now
+// and then more than one blank line between them. All numbers are
+// drawn, so every board wins in the end.
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..100).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+
+    let mut result = joined(&numbers, ",") + "\n";
+    for _ in 0..size.max(1) {
+        let width = if rng.chance(70) { 5 } else { 1 + rng.below(9) };
+
+        rng.shuffle(&mut numbers);
+        result.push_str(&"\n".repeat(if rng.chance(80) { 1 } else { 2 + rng.below(3) }));
+        result.push_str(&lines(numbers[..width * width].chunks(width).map(|row| {
+            row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ")
+        })));
+    }
+
+    result
+}
+
+// Horizontal, vertical and diagonal vents on a map that grows with `size`.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    let max = 10 + 5 * size as i64;
+    let directions = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+    lines((0..10 * size).map(|_| {
+        let (dx, dy) = *rng.pick(&directions);
+        let length = rng.range(1..=max / 2);
+        let start = |rng: &mut Rng, d: i64| match d {
+            1 => rng.range(0..=max - length),
+            -1 => rng.range(length..=max),
+            _ => rng.range(0..=max),
+        };
+        let (x, y) = (start(rng, dx), start(rng, dy));
+
+        format!("{},{} -> {},{}", x, y, x + dx * length, y + dy * length)
+    }))
+}
+
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let fish = (0..5 * size.max(1)).map(|_| rng.range(1..=5)).collect::<Vec<_>>();
+    joined(&fish, ",") + "\n"
+}
+
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    let crabs = (0..10 * size.max(1)).map(|_| rng.range(0..=20 * size as i64)).collect::<Vec<_>>();
+    joined(&crabs, ",") + "\n"
+}
+
+const SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+// Each display gets its own wiring, patterns and segments are shuffled.
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    lines((0..2 * size.max(1)).map(|_| {
+        let mut wiring = "abcdefg".chars().collect::<Vec<_>>();
+        rng.shuffle(&mut wiring);
+
+        let pattern = |rng: &mut Rng, digit: usize| {
+            let mut wires = SEGMENTS[digit].chars().map(|c| wiring[(c as u8 - b'a') as usize]).collect::<Vec<_>>();
+            rng.shuffle(&mut wires);
+            wires.into_iter().collect::<String>()
+        };
+
+        let mut digits = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut digits);
+        let patterns = digits.iter().map(|d| pattern(rng, *d)).collect::<Vec<_>>();
+        let output = (0..4).map(|_| { let d = rng.below(10); pattern(rng, d) }).collect::<Vec<_>>();
+
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    digits(rng, 5 + 2 * size, 3 + size, |rng| if rng.chance(25) { 9 } else { rng.below(9) as u8 })
+}
+
+// Incomplete and corrupted lines, about half of each.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let pairs = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+    lines((0..5 * size.max(1)).map(|_| {
+        let length = 2 + rng.below(40);
+        let corrupted = rng.chance(50);
+        let mut line = String::new();
+        let mut stack = vec![];
+
+        while line.len() < length || stack.is_empty() {
+            match stack.last() {
+                Some(close) if rng.chance(40) => {
+                    line.push(*close);
+                    stack.pop();
+                },
+                _ => {
+                    let (open, close) = *rng.pick(&pairs);
+                    line.push(open);
+                    stack.push(close);
+                },
+            }
+        }
+
+        if corrupted {
+            let expected = stack[stack.len() - 1];
+            let wrong = pairs.iter().map(|(_, c)| *c).filter(|c| *c != expected).collect::<Vec<_>>();
+            line.push(*rng.pick(&wrong));
+        }
+        line
+    }))
+}
+
+// How often days 11 and 25 draw a random input before they settle for
+// one that is sure to have an answer.
+const ATTEMPTS: usize = 5;
+
+// Some grids never flash all at once, those are drawn again. Big ones
+// hardly ever do, in the end every octopus gets the same energy, so they
+// all flash together right away.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    for _ in 0..ATTEMPTS {
+        let input = digits(rng, 3 + size, 3 + size, |rng| rng.below(10) as u8);
+        if day11::input_generator(&input).and_then(|g| day11::solve_part2(&g)).is_ok() {
+            return input;
+        }
+    }
+
+    let row = rng.below(10).to_string().repeat(3 + size);
+    lines((0..3 + size).map(|_| row.clone()))
+}
+
+// Big caves are only ever connected to small ones, otherwise there would
+// be infinitely many paths. The number of paths grows quickly with
+// `size`.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    let small = ["dc", "kj", "sa", "pj", "zg", "he", "fs", "sl", "wi", "tz"];
+    let big = ["HN", "LN", "DX", "RW", "KQ"];
+    let small = &small[..(1 + size).min(small.len())];
+    let big = &big[..(1 + size / 3).min(big.len())];
+
+    let mut edges = HashSet::new();
+    for b in big {
+        for _ in 0..1 + rng.below(3) {
+            edges.insert((b.to_string(), rng.pick(small).to_string()));
+        }
+    }
+    for s in small {
+        if rng.chance(50) {
+            let other = rng.pick(small);
+            if other != s {
+                edges.insert((s.to_string(), other.to_string()));
+            }
+        }
+    }
+
+    let caves = small.iter().chain(big.iter()).collect::<Vec<_>>();
+    for _ in 0..1 + rng.below(2) {
+        edges.insert(("start".to_owned(), rng.pick(&caves).to_string()));
+        edges.insert((rng.pick(&caves).to_string(), "end".to_owned()));
+    }
+
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    rng.shuffle(&mut edges);
+    lines(edges.into_iter().map(|(a, b)| if rng.chance(50) { format!("{}-{}", a, b) } else { format!("{}-{}", b, a) }))
+}
+
+// Every fold halves the paper, no dot ever ends up on a fold line.
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    let (mut width, mut height) = (5 + rng.below(5) as i64, 3 + rng.below(3) as i64);
+    let mut folds = vec![];
+
+    for _ in 0..1 + size.min(5) {
+        if rng.chance(50) {
+            folds.push(('x', width));
+            width = 2 * width + 1;
+        } else {
+            folds.push(('y', height));
+            height = 2 * height + 1;
+        }
+    }
+    folds.reverse();
+
+    let on_fold_line = |mut x: i64, mut y: i64| {
+        folds.iter().any(|(axis, at)| {
+            let c = if *axis == 'x' { &mut x } else { &mut y };
+            if *c > *at {
+                *c = 2 * at - *c;
+            }
+            *c == *at
+        })
+    };
+
+    let mut dots = HashSet::new();
+    while dots.len() < 5 + 5 * size {
+        let (x, y) = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        if !on_fold_line(x, y) {
+            dots.insert((x, y));
+        }
+    }
+
+    let mut dots = dots.into_iter().collect::<Vec<_>>();
+    dots.sort();
+    rng.shuffle(&mut dots);
+
+    lines(dots.iter().map(|(x, y)| format!("{},{}", x, y))) + "\n" + &lines(folds.iter().map(|(axis, at)| format!("fold along {}={}", axis, at)))
+}
+
+// There is a rule for every pair of elements.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    let elements = &['B', 'C', 'H', 'N', 'O', 'P', 'S', 'V', 'K', 'F'][..(2 + size).min(10)];
+    let template = (0..2 + 2 * size).map(|_| *rng.pick(elements)).collect::<String>();
+
+    let mut rules = vec![];
+    for a in elements {
+        for b in elements {
+            rules.push(format!("{}{} -> {}", a, b, rng.pick(elements)));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    template + "\n\n" + &lines(rules)
+}
+
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    let side = 5 + 2 * size;
+    digits(rng, side, side, |rng| 1 + rng.below(9) as u8)
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: usize) {
+    bits.extend((0..count).rev().map(|i| value >> i & 1 == 1));
+}
+
+// A random packet and its value, nested up to `depth` levels deep. The
+// operators are picked so that no value grows beyond 2^50.
+fn packet(rng: &mut Rng, depth: usize) -> (Vec<bool>, u64) {
+    let mut bits = vec![];
+    push_bits(&mut bits, rng.below(8) as u64, 3);
+
+    if depth == 0 || rng.chance(30) {
+        let nibbles = 1 + rng.below(5);
+        let value = rng.below(1 << (4 * nibbles)) as u64;
+        let groups = (1..=16).find(|g| value >> (4 * g) == 0).unwrap_or(16);
+
+        push_bits(&mut bits, 4, 3);
+        for g in (0..groups).rev() {
+            bits.push(g > 0);
+            push_bits(&mut bits, value >> (4 * g) & 0xf, 4);
+        }
+        return (bits, value);
+    }
+
+    let comparison = rng.chance(30);
+    let count = if comparison { 2 } else { 1 + rng.below(3) };
+    let packets = (0..count).map(|_| packet(rng, depth - 1)).collect::<Vec<_>>();
+    let values = packets.iter().map(|(_, v)| *v).collect::<Vec<_>>();
+    let limit = 1 << 50;
+
+    let (type_id, value) = if comparison {
+        match rng.below(3) {
+            0 => (5, (values[0] > values[1]) as u64),
+            1 => (6, (values[0] < values[1]) as u64),
+            _ => (7, (values[0] == values[1]) as u64),
+        }
+    } else {
+        let sum = values.iter().sum::<u64>();
+        let product = values.iter().try_fold(1u64, |a, v| a.checked_mul(*v).filter(|p| *p < limit));
+        let min = *values.iter().min().unwrap_or(&0);
+        let max = *values.iter().max().unwrap_or(&0);
+
+        match (rng.below(4), product) {
+            (1, Some(p)) => (1, p),
+            (0, _) | (1, None) if sum < limit => (0, sum),
+            (2, _) => (2, min),
+            _ => (3, max),
+        }
+    };
+
+    push_bits(&mut bits, type_id, 3);
+    let children = packets.into_iter().flat_map(|(b, _)| b).collect::<Vec<_>>();
+    if rng.chance(50) {
+        bits.push(false);
+        push_bits(&mut bits, children.len() as u64, 15);
+    } else {
+        bits.push(true);
+        push_bits(&mut bits, count as u64, 11);
+    }
+    bits.extend(children);
+
+    (bits, value)
+}
+
+// A transmission of packets nested up to `size` levels deep.
+pub fn day16(rng: &mut Rng, size: usize) -> String {
+    let (mut bits, _) = packet(rng, size.min(4));
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+
+    let hex = bits
+        .chunks(4)
+        .map(|nibble| nibble.iter().fold(0, |a, b| a << 1 | *b as u32))
+        .map(|n| std::char::from_digit(n, 16).unwrap_or('0').to_ascii_uppercase())
+        .collect::<String>();
+    hex + "\n"
+}
+
+pub fn day17(rng: &mut Rng, size: usize) -> String {
+    let x_min = rng.range(5..=10 + 10 * size as i64);
+    let y_min = rng.range(-60..=-10);
+
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_min + rng.range(3..=20), y_min, rng.range(y_min + 3..=-2)
+    )
+}
+
+// A snailfish number with no pair nested inside more than `deepest` pairs
+// and no number above `largest`. `depth` is the number of pairs around it.
+fn snailfish(rng: &mut Rng, depth: usize, deepest: usize, largest: usize) -> String {
+    if depth == deepest || (depth > 0 && rng.chance(40)) {
+        return rng.below(largest + 1).to_string();
+    }
+
+    format!("[{},{}]", snailfish(rng, depth + 1, deepest, largest), snailfish(rng, depth + 1, deepest, largest))
+}
+
+// Mostly reduced numbers, like the puzzle has them, but every now and then
+// one that still needs reducing, nested up to eight pairs deep.
+pub fn day18(rng: &mut Rng, size: usize) -> String {
+    lines((0..2 + size).map(|_| {
+        if rng.chance(25) {
+            let deepest = 5 + rng.below(4);
+            snailfish(rng, 0, deepest, 20)
+        } else {
+            snailfish(rng, 0, 4, 9)
+        }
+    }))
+}
+
+// The 24 rotations, as an axis permutation and the signs of the axes.
+fn rotations() -> Vec<([usize; 3], [isize; 3])> {
+    let permutations = [([0, 1, 2], 1), ([1, 2, 0], 1), ([2, 0, 1], 1), ([0, 2, 1], -1), ([2, 1, 0], -1), ([1, 0, 2], -1)];
+    let mut result = vec![];
+
+    for (axes, parity) in permutations {
+        for signs in 0..8 {
+            let signs = [1 - 2 * (signs & 1), 1 - (signs & 2), 1 - (signs & 4) / 2];
+            if parity * signs[0] * signs[1] * signs[2] == 1 {
+                result.push((axes, signs));
+            }
+        }
+    }
+
+    result
+}
+
+// `size` scanners, each of them sharing at least 12 beacons with one of
+// the scanners before it, so they can all be put together.
+pub fn day19(rng: &mut Rng, size: usize) -> String {
+    let range = 1000;
+    let mut scanners = vec![Point3::default()];
+    let mut beacons = HashSet::new();
+    let random_in = |rng: &mut Rng, min: Point3, max: Point3| {
+        Point3::new(
+            rng.range(min.x as i64..=max.x as i64) as isize,
+            rng.range(min.y as i64..=max.y as i64) as isize,
+            rng.range(min.z as i64..=max.z as i64) as isize,
+        )
+    };
+    let reach = Point3::new(range, range, range);
+
+    while scanners.len() < size.max(1) {
+        let other = *rng.pick(&scanners);
+        let offset = random_in(rng, Point3::new(-1100, -1100, -1100), Point3::new(1100, 1100, 1100));
+        let scanner = other + offset;
+        if scanners.contains(&scanner) {
+            continue;
+        }
+
+        let min = Point3::new(scanner.x.max(other.x), scanner.y.max(other.y), scanner.z.max(other.z)) - reach;
+        let max = Point3::new(scanner.x.min(other.x), scanner.y.min(other.y), scanner.z.min(other.z)) + reach;
+        for _ in 0..12 {
+            beacons.insert(random_in(rng, min, max));
+        }
+        scanners.push(scanner);
+    }
+
+    for scanner in &scanners {
+        for _ in 0..5 + rng.below(6) {
+            beacons.insert(random_in(rng, *scanner - reach, *scanner + reach));
+        }
+    }
+
+    let mut beacons = beacons.into_iter().collect::<Vec<_>>();
+    beacons.sort();
+    let rotations = rotations();
+
+    let reports = scanners
+        .iter()
+        .enumerate()
+        .map(|(i, scanner)| {
+            let (axes, signs) = *rng.pick(&rotations);
+            let mut seen = beacons
+                .iter()
+                .map(|b| *b - *scanner)
+                .filter(|b| b.chebyshev(Point3::default()) <= range as usize)
+                .map(|b| format!("{},{},{}", b[axes[0]] * signs[0], b[axes[1]] * signs[1], b[axes[2]] * signs[2]))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut seen);
+
+            format!("--- scanner {} ---\n{}", i, lines(seen))
+        })
+        .collect::<Vec<_>>();
+
+    reports.join("\n")
+}
+
+// If the filter lights up dark regions, it has to darken lit ones or
+// the infinite image would end up all lit.
+pub fn day20(rng: &mut Rng, size: usize) -> String {
+    let mut filter = (0..512).map(|_| *rng.pick(&['#', '.'])).collect::<Vec<_>>();
+    if filter[0] == '#' {
+        filter[511] = '.';
+    }
+
+    let side = 3 + 2 * size;
+    let image = lines((0..side).map(|_| (0..side).map(|_| *rng.pick(&['#', '.'])).collect::<String>()));
+
+    filter.into_iter().collect::<String>() + "\n\n" + &image
+}
+
+pub fn day21(rng: &mut Rng, _size: usize) -> String {
+    format!("Player 1 starting position: {}\nPlayer 2 starting position: {}\n", rng.range(1..=10), rng.range(1..=10))
+}
+
+// All cuboids lie within -50..50, the region of part 1, so both parts
+// count the same cubes.
+pub fn day22(rng: &mut Rng, size: usize) -> String {
+    lines((0..2 + 2 * size).map(|i| {
+        let mut bounds = vec![];
+        for _ in 0..3 {
+            let min = rng.range(-50..=45);
+            bounds.push((min, (min + rng.range(0..=15)).min(50)));
+        }
+
+        let state = if i == 0 || rng.chance(70) { "on" } else { "off" };
+        format!(
+            "{} x={}..{},y={}..{},z={}..{}",
+            state, bounds[0].0, bounds[0].1, bounds[1].0, bounds[1].1, bounds[2].0, bounds[2].1
+        )
+    }))
+}
+
+pub fn day23(rng: &mut Rng, _size: usize) -> String {
+    let mut amphipods = "AABBCCDD".chars().collect::<Vec<_>>();
+    rng.shuffle(&mut amphipods);
+
+    let row = |a: &[char]| a.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("#");
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(&amphipods[..4]), row(&amphipods[4..])
+    )
+}
+
+// A MONAD of seven pairs of blocks that push a digit onto z and pop it
+// again, whatever the size, as there are always 14 digits. Each pair
+// accepts at most four pairs of digits, that keeps the number of valid
+// model numbers down, but there always is one.
+pub fn day24(rng: &mut Rng, _size: usize) -> String {
+    let pairs = day24::DIGITS / 2;
+    let mut blocks = vec![];
+    let mut stack = vec![];
+    let mut pushed = 0;
+
+    while blocks.len() < 2 * pairs {
+        if pushed < pairs && (stack.is_empty() || rng.chance(50)) {
+            let offset = rng.range(0..=16);
+            blocks.push((1, rng.range(10..=16), offset));
+            stack.push(offset);
+            pushed += 1;
+        } else if let Some(offset) = stack.pop() {
+            let diff = rng.range(5..=8) * if rng.chance(50) { 1 } else { -1 };
+            blocks.push((26, diff - offset, rng.range(0..=16)));
+        }
+    }
+
+    lines(blocks.iter().flat_map(|(div, check, offset)| {
+        day24::BLOCK.iter().enumerate().map(move |(i, instruction)| match i {
+            4 => format!("{} {}", instruction, div),
+            5 => format!("{} {}", instruction, check),
+            15 => format!("{} {}", instruction, offset),
+            _ => instruction.to_string(),
+        })
+    }))
+}
+
+// Some herds keep moving forever, those are drawn again. In the end the
+// sea floor is packed so tightly that nobody can move at all.
+pub fn day25(rng: &mut Rng, size: usize) -> String {
+    for _ in 0..ATTEMPTS {
+        let input = herds(rng, size, 4);
+        if day25::input_generator(&input).and_then(|g| day25::solve_part1(&g)).is_ok() {
+            return input;
+        }
+    }
+
+    herds(rng, size, 0)
+}
+
+// Out of ten cells, `empty` are empty on average.
+fn herds(rng: &mut Rng, size: usize, empty: usize) -> String {
+    lines((0..4 + size).map(|_| {
+        (0..5 + 2 * size)
+            .map(|_| match rng.below(10) {
+                n if n < empty => '.',
+                4..=6 => '>',
+                _ => 'v',
+            })
+            .collect::<String>()
+    }))
+}
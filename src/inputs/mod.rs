@@ -0,0 +1,102 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use crate::utils::Error;
+
+pub const ROOT: &str = "inputs";
+pub const DEFAULT_PROFILE: &str = "default";
+
+// Everyone gets their own puzzle input, so the inputs of a day are kept
+// side by side as profiles, one per person:
+//
+// inputs/2021/day05/default.txt
+// inputs/2021/day05/alice.txt
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    root: PathBuf,
+    profile: String,
+}
+
+impl Inputs {
+    pub fn new(root: &Path, profile: &str) -> Self {
+        Inputs { root: root.to_owned(), profile: profile.to_owned() }
+    }
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join(format!("{}.txt", self.profile))
+    }
+
+    // The profiles that have an input for the day, sorted by name.
+    pub fn profiles(&self, year: u16, day: u8) -> Vec<String> {
+        let mut profiles = fs::read_dir(self.day_dir(year, day))
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+            .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+            .collect::<Vec<_>>();
+
+        profiles.sort();
+        profiles
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, Error> {
+        let path = self.path(year, day);
+
+        fs::read_to_string(&path)
+            .map_err(|e| {
+                let what = match e.kind() {
+                    ErrorKind::NotFound => match &self.profiles(year, day)[..] {
+                        [] => format!(
+                            "No input for day {} of {} (profile {}) at {}, save yours from https://adventofcode.com/{}/day/{}/input",
+                            day, year, self.profile, path.display(), year, day
+                        ),
+                        others => format!(
+                            "No input of profile {} at {}, there are inputs of {}",
+                            self.profile, path.display(), others.join(", ")
+                        ),
+                    },
+                    _ => format!("Cannot read {}", path.display()),
+                };
+
+                Error::io(&what, e).in_day(day)
+            })
+    }
+
+    fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{:02}", day))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn load_profiles() -> Result<(), Error> {
+        let root = std::env::temp_dir().join(format!("y2021-inputs-{}", std::process::id()));
+        let alice = Inputs::new(&root, "alice");
+        let bob = Inputs::new(&root, "bob");
+        fs::create_dir_all(root.join("2021/day05")).map_err(|e| Error::io("Cannot create directory", e))?;
+        fs::write(alice.path(2021, 5), "0,9 -> 5,9\n").map_err(|e| Error::io("Cannot write input", e))?;
+
+        let loaded = alice.load(2021, 5);
+        let missing = bob.load(2021, 5);
+        let no_day = bob.load(2021, 6);
+        fs::remove_dir_all(&root).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        assert_eq!(root.join("2021/day05/alice.txt"), alice.path(2021, 5));
+        assert_eq!("0,9 -> 5,9\n", loaded?);
+        assert!(missing.unwrap_err().what().ends_with("there are inputs of alice"));
+        assert_eq!(
+            format!("No input for day 6 of 2021 (profile bob) at {}, save yours from https://adventofcode.com/2021/day/6/input", bob.path(2021, 6).display()),
+            no_day.unwrap_err().what()
+        );
+        Ok(())
+    }
+}
@@ -7,33 +7,22 @@ extern crate regex;
 extern crate num;
 extern crate pathfinding;
 extern crate serde_json;
-extern crate memoize;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod fixtures;
+pub mod fuzz;
+pub mod gen;
+pub mod inputs;
+#[macro_use]
+pub mod log;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
+pub mod y2021;
 
+// cargo-aoc only knows about a single year, the one it runs.
 aoc_lib!{ year = 2021 }
\ No newline at end of file
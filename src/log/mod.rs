@@ -0,0 +1,171 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+use crate::utils::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => Level::Error,
+            "warn" => Level::Warn,
+            "info" => Level::Info,
+            "debug" => Level::Debug,
+            "trace" => Level::Trace,
+            _ => return Err(Error::token(s, s, "Expected error, warn, info, debug or trace")),
+        })
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+// How much each day logs, written like `info` for every day or
+// `warn,19=trace` for everything of day 19 but only warnings of the rest.
+// Days that are not mentioned and have no default log nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    days: Vec<(u8, Level)>,
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+
+        for part in s.split(',').filter(|p| !p.is_empty()) {
+            match part.split_once('=') {
+                Some((day, level)) => {
+                    let day = day.parse::<u8>().map_err(|e| Error::token(s, day, "Expected a day").with_source(e))?;
+                    filter.days.push((day, Level::from_str(level)?));
+                },
+                None => filter.default = Some(Level::from_str(part)?),
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+impl Filter {
+    pub fn level(&self, day: u8) -> Option<Level> {
+        self.days
+            .iter()
+            .rev()
+            .find(|(d, _)| *d == day)
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter { default: None, days: Vec::new() });
+
+pub fn set_filter(filter: Filter) {
+    if let Ok(mut f) = FILTER.write() {
+        *f = filter;
+    }
+}
+
+pub fn enabled(day: u8, level: Level) -> bool {
+    FILTER
+        .read()
+        .ok()
+        .and_then(|f| f.level(day))
+        .is_some_and(|max| level <= max)
+}
+
+// The day a module belongs to, 19 for y2021::day19 and its submodules.
+pub fn day_of(module: &str) -> Option<u8> {
+    module
+        .split("::")
+        .find_map(|m| m.strip_prefix("day"))
+        .and_then(|d| d.parse().ok())
+}
+
+// Everything is logged to stderr, stdout only ever has answers.
+pub fn log(module: &str, level: Level, args: fmt::Arguments) {
+    if let Some(day) = day_of(module) {
+        if enabled(day, level) {
+            eprintln!("[day {} {}] {}", day, level, args);
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        $crate::log::log(module_path!(), $level, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Error, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Warn, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Info, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Trace, $($arg)*) };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filter() -> Result<(), Error> {
+        let filter = Filter::from_str("warn,19=trace,22=error")?;
+
+        assert_eq!(Some(Level::Trace), filter.level(19));
+        assert_eq!(Some(Level::Error), filter.level(22));
+        assert_eq!(Some(Level::Warn), filter.level(1));
+        assert_eq!(None, Filter::from_str("19=debug")?.level(1));
+        assert!(Filter::from_str("19=loud").is_err());
+        Ok(assert!(Filter::from_str("x=debug").is_err()))
+    }
+
+    #[test]
+    fn day_of_module() {
+        assert_eq!(Some(19), day_of("y2021::y2021::day19"));
+        assert_eq!(Some(3), day_of("y2021::y2021::day03::report"));
+        assert_eq!(None, day_of("y2021::runner"));
+    }
+}
@@ -0,0 +1,170 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::utils::{Error, Grid};
+
+pub mod y2021;
+
+pub const ROOT: &str = "frames";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Pixels {
+    Grey(Vec<u8>),
+    Colour(Vec<[u8; 3]>),
+}
+
+// A picture of a puzzle state, one pixel per cell until it is scaled up.
+// Grey pictures are written as PGM files, coloured ones as PPM files,
+// both in their binary form so any image viewer can open them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Pixels,
+}
+
+impl Picture {
+    pub fn grey<T, F: Fn(&T) -> u8>(grid: &Grid<T>, f: F) -> Self {
+        Picture {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: Pixels::Grey(grid.rows().flatten().map(f).collect()),
+        }
+    }
+
+    pub fn colour<T, F: Fn(&T) -> [u8; 3]>(grid: &Grid<T>, f: F) -> Self {
+        Picture {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: Pixels::Colour(grid.rows().flatten().map(f).collect()),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Every pixel becomes a square of `factor` pixels, puzzle grids are
+    // usually too small to make out anything otherwise.
+    pub fn scaled(&self, factor: usize) -> Self {
+        fn scale<P: Copy>(pixels: &[P], width: usize, factor: usize) -> Vec<P> {
+            pixels
+                .chunks(width.max(1))
+                .flat_map(|row| {
+                    let row = row.iter().flat_map(|p| std::iter::repeat_n(*p, factor)).collect::<Vec<_>>();
+                    std::iter::repeat_n(row, factor).flatten()
+                })
+                .collect()
+        }
+
+        let pixels = match &self.pixels {
+            Pixels::Grey(p) => Pixels::Grey(scale(p, self.width, factor)),
+            Pixels::Colour(p) => Pixels::Colour(scale(p, self.width, factor)),
+        };
+
+        Picture { width: self.width * factor, height: self.height * factor, pixels }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self.pixels {
+            Pixels::Grey(_) => "pgm",
+            Pixels::Colour(_) => "ppm",
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let (magic, bytes) = match &self.pixels {
+            Pixels::Grey(p) => ("P5", p.clone()),
+            Pixels::Colour(p) => ("P6", p.iter().flatten().copied().collect()),
+        };
+
+        let mut result = format!("{}\n{} {}\n255\n", magic, self.width, self.height).into_bytes();
+        result.extend(bytes);
+        result
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.encode()).map_err(|e| Error::io(&format!("Cannot write {}", path.display()), e))
+    }
+}
+
+// Puzzle states that can be drawn on their own.
+pub trait Render {
+    fn render(&self) -> Picture;
+}
+
+// Writes `pictures` to `dir` as name-0000.pgm, name-0001.pgm and so on,
+// and returns the paths in order.
+pub fn save_frames(dir: &Path, name: &str, pictures: &[Picture]) -> Result<Vec<PathBuf>, Error> {
+    fs::create_dir_all(dir).map_err(|e| Error::io(&format!("Cannot create {}", dir.display()), e))?;
+
+    pictures
+        .iter()
+        .enumerate()
+        .map(|(i, picture)| {
+            let path = dir.join(format!("{}-{:04}.{}", name, i, picture.extension()));
+            picture.save(&path)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+// Turns an input into the frames of its simulation, at most `steps` steps
+// after the initial state.
+pub type Animation = fn(&str, usize) -> Result<Vec<Picture>, Error>;
+
+pub fn animation(year: u16, day: u8) -> Option<Animation> {
+    match year {
+        2021 => y2021::animation(day),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use crate::solution;
+
+    #[test]
+    fn encode() -> Result<(), Error> {
+        let grid = Grid::from_rows(vec![vec![0u8, 1], vec![2, 3]])?;
+
+        assert_eq!(b"P5\n2 2\n255\n\x00\x01\x02\x03".to_vec(), Picture::grey(&grid, |v| *v).encode());
+        assert_eq!(b"P6\n2 1\n255\n\x00\x00\x00\x01\x01\x01".to_vec(), Picture::colour(&Grid::from_rows(vec![vec![0u8, 1]])?, |v| [*v; 3]).encode());
+        Ok(assert_eq!(b"P5\n4 2\n255\n\x00\x00\x01\x01\x00\x00\x01\x01".to_vec(), Picture::grey(&Grid::from_rows(vec![vec![0u8, 1]])?, |v| *v).scaled(2).encode()))
+    }
+
+    #[test]
+    fn animate_fixtures() -> Result<(), Error> {
+        for day in solution::years().iter().flat_map(|y| y.days.iter()) {
+            let animate = match animation(day.year, day.day) {
+                Some(a) => a,
+                None => continue,
+            };
+
+            for fixture in fixtures::load(Path::new(fixtures::ROOT), day.year, day.day)? {
+                let frames = animate(&fixture.input, 3)?;
+
+                assert!(!frames.is_empty() && frames.len() <= 4, "day {} {}", day.day, fixture.name);
+                assert!(frames.iter().all(|f| f.encode().len() > f.width() * f.height()), "day {} {}", day.day, fixture.name);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn frames_are_numbered() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!("y2021-frames-{}", std::process::id()));
+        let picture = Picture::grey(&Grid::new(2, 3, 7u8), |v| *v);
+        let saved = save_frames(&dir, "day11", &[picture.clone(), picture.clone()]);
+        let read = fs::read(dir.join("day11-0001.pgm"));
+        fs::remove_dir_all(&dir).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        assert_eq!(vec![dir.join("day11-0000.pgm"), dir.join("day11-0001.pgm")], saved?);
+        Ok(assert_eq!(picture.encode(), read.map_err(|e| Error::io("Cannot read frame", e))?))
+    }
+}
@@ -0,0 +1,14 @@
+use crate::render::Animation;
+use crate::y2021::{day11, day13, day20, day25};
+
+pub fn animation(day: u8) -> Option<Animation> {
+    let animation: Animation = match day {
+        11 => |input, steps| day11::frames(&day11::input_generator(input)?, steps),
+        13 => |input, steps| day13::frames(&day13::input_generator(input)?, steps),
+        20 => |input, steps| day20::frames(&day20::input_generator(input)?, steps),
+        25 => |input, steps| day25::frames(&day25::input_generator(input)?, steps),
+        _ => return None,
+    };
+
+    Some(animation)
+}
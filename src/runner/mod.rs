@@ -0,0 +1,262 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::bench::format_duration;
+use crate::budget::Budget;
+use crate::inputs::Inputs;
+use serde_json::{json, Value};
+use crate::solution::{self, Answer, Artefact, Day};
+use crate::utils::Error;
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub duration: Duration,
+    pub artefacts: Vec<Artefact>,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub generator: Result<Duration, Error>,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn failures(&self) -> usize {
+        self.parts.iter().filter(|p| p.answer.is_err()).count()
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+// Runs `f`, turning a panic into an error so a single broken solver
+// doesn't take all the others down with it.
+fn isolate<T, F>(day: u8, f: F) -> Result<T, Error>
+where
+    F: FnOnce() -> Result<T, Error>,
+{
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|e| Err(Error::solve(&format!("Panicked: {}", panic_message(e))).in_day(day)))
+}
+
+fn timed<T, F>(day: u8, f: F) -> (Result<T, Error>, Duration)
+where
+    F: FnOnce() -> Result<T, Error>,
+{
+    let start = Instant::now();
+    let result = isolate(day, f);
+    (result, start.elapsed())
+}
+
+// Every part gets the whole `budget` to itself.
+fn run_part(day: &Day, generated: &dyn Any, part: u8, budget: Option<&Budget>) -> PartReport {
+    let solve = || match budget {
+        Some(b) => day.solve_within(generated, part, &mut b.restart()),
+        None => day.solve(generated, part),
+    };
+    let ((answer, duration), artefacts) = solution::collect_artefacts(|| timed(day.day, solve));
+    PartReport { part, answer, duration, artefacts }
+}
+
+pub fn run_day(day: &Day, input: Result<String, Error>, parts: &[u8], budget: Option<&Budget>) -> Report {
+    let (generated, duration) = match input {
+        Ok(input) => timed(day.day, || day.generate(&input)),
+        Err(e) => (Err(e), Duration::default()),
+    };
+
+    let (generator, parts) = match generated {
+        Ok(generated) => {
+            let parts = parts
+                .iter()
+                .map(|part| run_part(day, generated.as_ref(), *part, budget))
+                .collect();
+
+            (Ok(duration), parts)
+        },
+        Err(e) => {
+            let parts = parts
+                .iter()
+                .map(|part| PartReport {
+                    part: *part,
+                    answer: Err(Error::solve("Generator failed").in_day(day.day)),
+                    duration: Duration::default(),
+                    artefacts: vec![],
+                })
+                .collect();
+
+            (Err(e), parts)
+        },
+    };
+
+    Report { year: day.year, day: day.day, generator, parts }
+}
+
+// Runs every day in `days` on its input from `inputs`, spread over
+// `threads` worker threads. Reports are sorted by year and day.
+pub fn run_all(days: &[&Day], inputs: &Inputs, threads: usize, budget: Option<&Budget>) -> Vec<Report> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..threads.max(1) {
+            let tx = tx.clone();
+            let next = &next;
+
+            s.spawn(move || {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::SeqCst)) {
+                    if tx.send(run_day(day, inputs.load(day.year, day.day), &[1, 2], budget)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut reports = rx.into_iter().collect::<Vec<_>>();
+    reports.sort_by_key(|r| (r.year, r.day));
+    reports
+}
+
+pub fn table(reports: &[Report]) -> String {
+    let mut result = format!("{:>4} {:>3}  {:<10} {:>10}  {}\n", "year", "day", "stage", "time", "result");
+
+    for r in reports {
+        let line = match &r.generator {
+            Ok(d) => format!("{:>4} {:>3}  {:<10} {:>10}", r.year, r.day, "generator", format_duration(*d)),
+            Err(e) => format!("{:>4} {:>3}  {:<10} {:>10}  failed: {}", r.year, r.day, "generator", "-", e),
+        };
+        result.push_str(&line);
+        result.push('\n');
+
+        for p in &r.parts {
+            let stage = format!("part{}", p.part);
+            let line = match &p.answer {
+                Ok(a) => format!("{:>4} {:>3}  {:<10} {:>10}  {}", r.year, r.day, stage, format_duration(p.duration), a),
+                Err(e) => format!("{:>4} {:>3}  {:<10} {:>10}  failed: {}", r.year, r.day, stage, "-", e),
+            };
+            result.push_str(&line);
+            result.push('\n');
+        }
+    }
+
+    let failures = reports.iter().map(|r| r.failures()).sum::<usize>();
+    let answers = reports.iter().map(|r| r.parts.len()).sum::<usize>() - failures;
+    result.push_str(&format!("{} days, {} answers, {} failures\n", reports.len(), answers, failures));
+
+    result
+}
+
+// Durations are given in nanoseconds, like in the benchmark output.
+pub fn json(reports: &[Report]) -> Value {
+    let entries = reports
+        .iter()
+        .map(|r| {
+            let generator = match &r.generator {
+                Ok(d) => json!({ "time_ns": d.as_nanos() as u64 }),
+                Err(e) => json!({ "error": e.to_string() }),
+            };
+            let parts = r.parts
+                .iter()
+                .map(|p| {
+                    let artefacts = p.artefacts
+                        .iter()
+                        .map(|a| json!({ "name": a.name, "content": a.content }))
+                        .collect::<Vec<_>>();
+
+                    match &p.answer {
+                        Ok(a) => json!({ "part": p.part, "answer": a.to_json(), "time_ns": p.duration.as_nanos() as u64, "artefacts": artefacts }),
+                        Err(Error::BudgetExhausted { steps, elapsed, .. }) => json!({
+                            "part": p.part,
+                            "error": p.answer.as_ref().unwrap_err().to_string(),
+                            "budget_exhausted": { "steps": steps, "time_ns": elapsed.as_nanos() as u64 },
+                            "artefacts": artefacts,
+                        }),
+                        Err(e) => json!({ "part": p.part, "error": e.to_string(), "artefacts": artefacts }),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "year": r.year, "day": r.day, "generator": generator, "parts": parts })
+        })
+        .collect::<Vec<_>>();
+
+    Value::Array(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::Path;
+    use crate::solution;
+
+    #[test]
+    fn panics_are_isolated() {
+        let result: Result<(), Error> = isolate(3, || panic!("boom"));
+        let error = result.unwrap_err();
+
+        assert_eq!(Some(3), error.location().day);
+        assert!(error.to_string().contains("Panicked: boom"));
+    }
+
+    #[test]
+    fn run_single_day() -> Result<(), Error> {
+        let day = solution::day(2021, 1).ok_or(Error::new("Day 1 is missing"))?;
+        let report = run_day(day, Ok("199\n200\n208\n210\n200\n207\n240\n269\n260\n263".to_owned()), &[1, 2], None);
+
+        assert!(report.generator.is_ok());
+        assert_eq!(Some(&Answer::Number(7)), report.parts[0].answer.as_ref().ok());
+        assert_eq!(0, report.failures());
+        Ok(())
+    }
+
+    #[test]
+    fn json_contains_artefacts() -> Result<(), Error> {
+        let day = solution::day(2021, 13).ok_or(Error::new("Day 13 is missing"))?;
+        let report = run_day(day, Ok("0,0\n1,1\n\nfold along y=1\n".to_owned()), &[2], None);
+        let json = json(&[report]);
+
+        assert_eq!(json!(13), json[0]["day"]);
+        assert_eq!(json!(2), json[0]["parts"][0]["part"]);
+        assert_eq!(json!("letters"), json[0]["parts"][0]["artefacts"][0]["name"]);
+        Ok(())
+    }
+
+    #[test]
+    fn budgets_run_out() -> Result<(), Error> {
+        let day = solution::day(2021, 25).ok_or(Error::new("Day 25 is missing"))?;
+        let input = include_str!("../../fixtures/2021/day25/sample.txt").to_owned();
+        let report = run_day(day, Ok(input.clone()), &[1], Some(&Budget::steps(10)));
+        let json = json(&[report]);
+        let error = json[0]["parts"][0]["error"].as_str().unwrap_or_default();
+
+        assert_eq!(json!(10), json[0]["parts"][0]["budget_exhausted"]["steps"]);
+        assert!(error.starts_with("day 25: Budget exhausted after 10 steps in "));
+        assert!(error.ends_with(": The sea cucumbers are still moving after 10 steps"));
+
+        let report = run_day(day, Ok(input), &[1], Some(&Budget::steps(100)));
+        Ok(assert_eq!(Some(&Answer::Number(58)), report.parts[0].answer.as_ref().ok()))
+    }
+
+    #[test]
+    fn missing_inputs_are_failures() {
+        let days = solution::latest().days.iter().take(3).collect::<Vec<_>>();
+        let reports = run_all(&days, &Inputs::new(Path::new("does/not/exist"), "default"), 2, None);
+
+        assert_eq!(vec![1, 2, 3], reports.iter().map(|r| r.day).collect::<Vec<_>>());
+        assert!(reports.iter().all(|r| r.generator.is_err() && r.failures() == 2));
+        assert!(table(&reports).ends_with("3 days, 0 answers, 6 failures\n"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::fixtures;
+use crate::utils::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Scaffold {
+    pub module: PathBuf,
+    pub fixture: PathBuf,
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(&format!("Cannot read {}", path.display()), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| Error::io(&format!("Cannot create {}", dir.display()), e))?;
+    }
+
+    fs::write(path, contents).map_err(|e| Error::io(&format!("Cannot write {}", path.display()), e))
+}
+
+fn module_number(prefix: &str) -> impl Fn(&str) -> Option<u16> + '_ {
+    move |line| line.trim().strip_prefix(prefix)?.strip_suffix(';')?.parse().ok()
+}
+
+fn entry_number(prefix: &str) -> impl Fn(&str) -> Option<u16> + '_ {
+    move |line| line.trim().strip_prefix(prefix)?.split(',').next()?.parse().ok()
+}
+
+// Adds `entry` to the lines of `contents` for which `number_of` returns a
+// number, keeping them ordered by it. The entry gets the indentation of
+// the lines around it.
+fn insert_sorted<F>(contents: &str, entry: &str, number: u16, number_of: F) -> Result<String, Error>
+where
+    F: Fn(&str) -> Option<u16>,
+{
+    let lines = contents.lines().collect::<Vec<_>>();
+    let numbers = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| number_of(l).map(|n| (i, n)))
+        .collect::<Vec<_>>();
+
+    if numbers.iter().any(|(_, n)| *n == number) {
+        return Err(Error::new(&format!("{} is already registered", number)));
+    }
+
+    let (last, _) = numbers.last().ok_or(Error::new("Cannot find where to register it"))?;
+    let position = numbers.iter().find(|(_, n)| *n > number).map_or(last + 1, |(i, _)| *i);
+    let neighbour = lines[position.min(*last)];
+    let indentation = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+
+    let mut result = lines[..position].join("\n");
+    result.push('\n');
+    result.push_str(indentation);
+    result.push_str(entry);
+    for l in &lines[position..] {
+        result.push('\n');
+        result.push_str(l);
+    }
+    if contents.ends_with('\n') {
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+// The year cargo-aoc runs, from the aoc_lib! call in lib.rs.
+fn aoc_year(lib: &str) -> Option<u16> {
+    lib.lines().find_map(|l| l.trim().strip_prefix("aoc_lib!{ year = ")?.strip_suffix('}')?.trim().parse().ok())
+}
+
+fn registry_entry(year: u16, day: u8) -> String {
+    format!("Day {{ year: {}, day: {}, solution: &day{:02}::Day{:02} }},", year, day, day, day)
+}
+
+// Creates src/yYYYY/dayNN/mod.rs from the template in the crate at
+// `root`, registers it with its year and adds an empty sample fixture
+// without any expected answers. A year seen for the first time gets its
+// own module. Nothing is touched if the day already exists.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Scaffold, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(&format!("There is no day {} in an advent calendar", day)));
+    }
+
+    let name = format!("day{:02}", day);
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let module = year_dir.join(&name).join("mod.rs");
+    let fixture = fixtures::dir(&root.join(fixtures::ROOT), year, day).join("sample.txt");
+    let year_module = year_dir.join("mod.rs");
+
+    if module.exists() {
+        return Err(Error::new(&format!("{} already exists", module.display())));
+    }
+
+    let lib = root.join("src/lib.rs");
+    let lib_contents = read(&lib)?;
+    let mut template = read(&root.join("src/template/mod.rs"))?
+        .replace("dayXX", &format!("day{}", day))
+        .replace("DayXX", &format!("Day{:02}", day));
+
+    // cargo-aoc registers solutions by day only, so days of any other year
+    // would clash with the ones it runs.
+    if aoc_year(&lib_contents) != Some(year) {
+        template = template
+            .lines()
+            .filter(|l| !l.starts_with("#[aoc"))
+            .map(|l| format!("{}\n", l))
+            .collect();
+    }
+
+    let mut files = vec![(module.clone(), template)];
+    if year_module.exists() {
+        let entry_prefix = format!("Day {{ year: {}, day: ", year);
+        let contents = read(&year_module)?;
+        let contents = insert_sorted(&contents, &format!("pub mod {};", name), day as u16, module_number("pub mod day"))?;
+        let contents = insert_sorted(&contents, &registry_entry(year, day), day as u16, entry_number(&entry_prefix))?;
+        files.push((year_module, contents));
+    } else {
+        let registry = root.join("src/solution/mod.rs");
+        let contents = format!("use crate::solution::Day;\n\npub mod {};\n\npub static DAYS: &[Day] = &[\n    {}\n];\n", name, registry_entry(year, day));
+        let lib_contents = insert_sorted(&lib_contents, &format!("pub mod y{};", year), year, module_number("pub mod y"))?;
+        let registry_contents = insert_sorted(
+            &read(&registry)?,
+            &format!("Year {{ year: {}, days: y{}::DAYS }},", year, year),
+            year,
+            entry_number("Year { year: "),
+        )?;
+
+        files.push((year_module, contents));
+        files.push((lib, lib_contents));
+        files.push((registry, registry_contents));
+    }
+
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    if !fixture.exists() {
+        write(&fixture, "")?;
+        write(&fixture.with_extension("json"), "{}\n")?;
+    }
+
+    Ok(Scaffold { module, fixture })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_days_ordered() -> Result<(), Error> {
+        let lib = "extern crate regex;\n\npub mod day01;\npub mod day03;\npub mod utils;\n";
+
+        assert_eq!(
+            "extern crate regex;\n\npub mod day01;\npub mod day02;\npub mod day03;\npub mod utils;\n",
+            insert_sorted(lib, "pub mod day02;", 2, module_number("pub mod day"))?
+        );
+        assert_eq!(
+            "extern crate regex;\n\npub mod day01;\npub mod day03;\npub mod day04;\npub mod utils;\n",
+            insert_sorted(lib, "pub mod day04;", 4, module_number("pub mod day"))?
+        );
+        assert!(insert_sorted(lib, "pub mod day03;", 3, module_number("pub mod day")).is_err());
+        Ok(())
+    }
+
+    fn crate_root(name: &str) -> Result<PathBuf, Error> {
+        let root = std::env::temp_dir().join(format!("y2021-scaffold-{}-{}", name, std::process::id()));
+        let template = read(Path::new("src/template/mod.rs"))?;
+        write(&root.join("src/template/mod.rs"), &template)?;
+        write(&root.join("src/lib.rs"), "pub mod solution;\npub mod y2021;\n\naoc_lib!{ year = 2021 }")?;
+        write(&root.join("src/solution/mod.rs"), "static YEARS: &[Year] = &[\n    Year { year: 2021, days: y2021::DAYS },\n];\n")?;
+        write(&root.join("src/y2021/mod.rs"), "pub mod day01;\n\npub static DAYS: &[Day] = &[\n    Day { year: 2021, day: 1, solution: &day01::Day01 },\n];\n")?;
+        write(&root.join("src/y2021/day01/mod.rs"), "")?;
+        Ok(root)
+    }
+
+    #[test]
+    fn scaffold_new_day() -> Result<(), Error> {
+        let root = crate_root("day")?;
+
+        let result = scaffold(&root, 2021, 7);
+        let existing = scaffold(&root, 2021, 1);
+        let module = read(&root.join("src/y2021/day07/mod.rs"));
+        let year = read(&root.join("src/y2021/mod.rs"));
+        let fixture = root.join("fixtures/2021/day07/sample.txt").exists();
+        let answers = read(&root.join("fixtures/2021/day07/sample.json"));
+        fs::remove_dir_all(&root).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        result?;
+        assert!(existing.is_err());
+        assert!(module?.contains("#[aoc(day7, part1)]\npub fn solve_part1"));
+        assert_eq!("pub mod day01;\npub mod day07;\n\npub static DAYS: &[Day] = &[\n    Day { year: 2021, day: 1, solution: &day01::Day01 },\n    Day { year: 2021, day: 7, solution: &day07::Day07 },\n];\n", year?);
+        assert!(fixture);
+        assert_eq!("{}\n", answers?);
+        Ok(())
+    }
+
+    #[test]
+    fn scaffold_new_year() -> Result<(), Error> {
+        let root = crate_root("year")?;
+
+        let result = scaffold(&root, 2022, 1);
+        let module = read(&root.join("src/y2022/day01/mod.rs"));
+        let year = read(&root.join("src/y2022/mod.rs"));
+        let lib = read(&root.join("src/lib.rs"));
+        let registry = read(&root.join("src/solution/mod.rs"));
+        fs::remove_dir_all(&root).map_err(|e| Error::io("Cannot remove directory", e))?;
+
+        result?;
+        assert!(!module?.contains("#[aoc"));
+        assert!(year?.contains("pub mod day01;\n\npub static DAYS: &[Day] = &[\n    Day { year: 2022, day: 1, solution: &day01::Day01 },\n];"));
+        assert_eq!("pub mod solution;\npub mod y2021;\npub mod y2022;\n\naoc_lib!{ year = 2021 }", lib?);
+        assert!(registry?.contains("    Year { year: 2021, days: y2021::DAYS },\n    Year { year: 2022, days: y2022::DAYS },\n];"));
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use num::{BigInt, BigUint, ToPrimitive};
+use serde_json::Value;
+use crate::budget::Budget;
+use crate::utils::Error;
+use crate::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    // Only numbers that don't fit into a Number, so equal answers are
+    // always the same variant.
+    Big(BigInt),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Answer {
+    // Numbers that don't fit into JSON's integers are written as strings.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Number(n) => i64::try_from(*n).map_or_else(|_| Value::from(n.to_string()), Value::from),
+            Answer::Big(n) => Value::from(n.to_string()),
+            Answer::Text(s) => Value::from(s.as_str()),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Answer::Number(v as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u32, u64, usize, i32, i64, i128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(v: BigInt) -> Self {
+        match v.to_i128() {
+            Some(n) => Answer::Number(n),
+            None => Answer::Big(v),
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(v: u128) -> Self {
+        BigInt::from(v).into()
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(v: BigUint) -> Self {
+        BigInt::from(v).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+// Something a solver produces besides its answer, like the letters day 13
+// draws on the paper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artefact {
+    pub name: String,
+    pub content: String,
+}
+
+thread_local! {
+    static ARTEFACTS: RefCell<Option<Vec<Artefact>>> = const { RefCell::new(None) };
+}
+
+// Hands an artefact to whoever runs the solver through collect_artefacts.
+// Without one, e.g. when running through cargo-aoc, it goes to stderr.
+pub fn artefact(name: &str, content: String) {
+    let artefact = Artefact { name: name.to_owned(), content };
+
+    let unclaimed = ARTEFACTS.with(|a| match a.borrow_mut().as_mut() {
+        Some(artefacts) => {
+            artefacts.push(artefact);
+            None
+        },
+        None => Some(artefact),
+    });
+
+    if let Some(a) = unclaimed {
+        eprintln!("{}:\n{}", a.name, a.content);
+    }
+}
+
+// Runs `f` and returns all artefacts it produced on this thread.
+pub fn collect_artefacts<T, F: FnOnce() -> T>(f: F) -> (T, Vec<Artefact>) {
+    let previous = ARTEFACTS.with(|a| a.replace(Some(vec![])));
+    let result = f();
+    let artefacts = ARTEFACTS.with(|a| a.replace(previous));
+
+    (result, artefacts.unwrap_or_default())
+}
+
+// Every day implements this on top of its input_generator and
+// solve_part1/solve_part2 functions, so those stay usable with cargo-aoc.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+
+    // Parts that might run for a very long time override these to keep to
+    // `budget`, the others ignore it.
+    fn part1_within(input: &Self::Input, _budget: &mut Budget) -> Result<Answer, Error> {
+        Self::part1(input)
+    }
+
+    fn part2_within(input: &Self::Input, _budget: &mut Budget) -> Result<Answer, Error> {
+        Self::part2(input)
+    }
+}
+
+// Object safe version of Solution, so days with different input types
+// can live in the same registry.
+pub trait DynSolution: Send + Sync {
+    fn generate(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<Answer, Error>;
+    fn solve_within(&self, input: &dyn Any, part: u8, budget: &mut Budget) -> Result<Answer, Error>;
+}
+
+impl<S: Solution + Send + Sync> DynSolution for S {
+    fn generate(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<Answer, Error> {
+        match part {
+            1 => S::part1(downcast::<S>(input)?),
+            2 => S::part2(downcast::<S>(input)?),
+            _ => Err(Error::solve(&format!("There is no part {}", part))),
+        }
+    }
+
+    fn solve_within(&self, input: &dyn Any, part: u8, budget: &mut Budget) -> Result<Answer, Error> {
+        match part {
+            1 => S::part1_within(downcast::<S>(input)?, budget),
+            2 => S::part2_within(downcast::<S>(input)?, budget),
+            _ => Err(Error::solve(&format!("There is no part {}", part))),
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input, Error> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or(Error::solve("Input was not generated by this day"))
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub fn generate(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        self.solution.generate(input).map_err(|e| e.in_day(self.day))
+    }
+
+    pub fn solve(&self, input: &dyn Any, part: u8) -> Result<Answer, Error> {
+        self.solution.solve(input, part).map_err(|e| e.in_day(self.day))
+    }
+
+    // Like solve, but parts that could run for very long keep to `budget`
+    // instead of their own limits.
+    pub fn solve_within(&self, input: &dyn Any, part: u8, budget: &mut Budget) -> Result<Answer, Error> {
+        self.solution.solve_within(input, part, budget).map_err(|e| e.in_day(self.day))
+    }
+
+    pub fn run(&self, input: &str, part: u8) -> Result<Answer, Error> {
+        let input = self.generate(input)?;
+        self.solve(input.as_ref(), part)
+    }
+}
+
+// All days of one event, each year lives in its own module like y2021.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+static YEARS: &[Year] = &[
+    Year { year: 2021, days: y2021::DAYS },
+];
+
+pub fn years() -> &'static [Year] {
+    YEARS
+}
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+// The most recent event, used whenever no year is given.
+pub fn latest() -> &'static Year {
+    &YEARS[YEARS.len() - 1]
+}
+
+pub fn day(year: u16, day: u8) -> Option<&'static Day> {
+    self::year(year)?.days.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registry_is_complete() {
+        for year in years() {
+            let numbers = year.days.iter().map(|d| d.day).collect::<Vec<_>>();
+            assert_eq!((1..=25).collect::<Vec<_>>(), numbers);
+            assert!(year.days.iter().all(|d| d.year == year.year));
+        }
+        assert_eq!(2021, latest().year);
+    }
+
+    #[test]
+    fn run_through_registry() -> Result<(), Error> {
+        let day = day(2021, 1).ok_or(Error::new("Day 1 is missing"))?;
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+        assert_eq!(Answer::Number(7), day.run(input, 1)?);
+        assert_eq!(Answer::Number(5), day.run(input, 2)?);
+        assert!(day.run(input, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn artefacts_are_collected() {
+        let (answer, artefacts) = collect_artefacts(|| {
+            artefact("picture", "#.#".to_owned());
+            42
+        });
+
+        assert_eq!(42, answer);
+        assert_eq!(vec![Artefact { name: "picture".to_owned(), content: "#.#".to_owned() }], artefacts);
+        assert!(collect_artefacts(|| ()).1.is_empty());
+    }
+
+    #[test]
+    fn big_answers() {
+        let big = BigInt::from(i128::MAX) * BigInt::from(1000);
+
+        assert_eq!(Answer::Number(12), BigUint::from(12u8).into());
+        assert_eq!(Answer::Big(big.clone()), big.clone().into());
+        assert_eq!(Value::from("170141183460469231731687303715884105727000"), Answer::from(big).to_json());
+    }
+}
@@ -1,47 +1,36 @@
-use std::num::ParseIntError;
-use crate::utils::ParseError;
+use crate::utils::{parse_lines, parse_token, Error};
+use crate::solution::{Answer, Solution};
 
 #[aoc_generator(dayXX)]
-pub fn input_generator(input: &str) -> Result<Vec<i32>, ParseIntError> {
-    input
-        .lines()
-        .filter(|s| *s != "")
-        .map(|s| s.parse::<i32>())
-        .collect::<Result<Vec<_>, ParseIntError>>()
+pub fn input_generator(input: &str) -> Result<Vec<i32>, Error> {
+    parse_lines(input, |s| parse_token(s, s))
 }
 
 #[aoc(dayXX, part1)]
-pub fn solve_part1(input: &Vec<i32>) -> Result<i32, ParseError> {
+pub fn solve_part1(input: &Vec<i32>) -> Result<i32, Error> {
     Ok(0)
 }
 
 #[aoc(dayXX, part2)]
-pub fn solve_part2(input: &Vec<i32>) -> Result<i32, ParseError> {
+pub fn solve_part2(input: &Vec<i32>) -> Result<i32, Error> {
     Ok(0)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::utils::ParseError;
+pub struct DayXX;
 
-    fn sample() -> &'static str {
-        ""
-    }
+impl Solution for DayXX {
+    type Input = Vec<i32>;
 
-    fn input() -> Result<Vec<i32>, ParseError> {
-        Ok(input_generator(sample())?)
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
     }
 
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(0, solve_part1(&data)?))
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
     }
 
-    #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(0, solve_part2(&data)?))
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
     }
 }
+
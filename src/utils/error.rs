@@ -0,0 +1,230 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use std::num::{ParseIntError, ParseFloatError};
+use std::sync::Arc;
+use std::time::Duration;
+
+type Source = Arc<dyn StdError + Send + Sync>;
+
+// Where in the puzzle input something went wrong. Lines and columns
+// start at 1, like in every editor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+  pub day: Option<u8>,
+  pub line: Option<usize>,
+  pub column: Option<usize>,
+}
+
+impl fmt::Display for Location {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut parts = vec![];
+
+    if let Some(day) = self.day {
+      parts.push(format!("day {}", day));
+    }
+    if let Some(line) = self.line {
+      parts.push(format!("line {}", line));
+    }
+    if let Some(column) = self.column {
+      parts.push(format!("column {}", column));
+    }
+
+    write!(f, "{}", parts.join(", "))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  // The puzzle input could not be turned into the day's input type.
+  Parse {
+    what: String,
+    token: Option<String>,
+    location: Location,
+    source: Option<Source>,
+  },
+  // The input was fine, but the solver could not come up with an answer.
+  Solve {
+    what: String,
+    location: Location,
+  },
+  // Reading or writing a file failed.
+  Io {
+    what: String,
+    location: Location,
+    source: Source,
+  },
+  // The solver used up its time or step budget before it was done.
+  BudgetExhausted {
+    what: String,
+    steps: u64,
+    elapsed: Duration,
+    location: Location,
+  },
+}
+
+impl Error {
+  pub fn new(s: &str) -> Error {
+    Error::Parse { what: s.to_string(), token: None, location: Location::default(), source: None }
+  }
+
+  pub fn solve(s: &str) -> Error {
+    Error::Solve { what: s.to_string(), location: Location::default() }
+  }
+
+  pub fn io(s: &str, error: io::Error) -> Error {
+    Error::Io { what: s.to_string(), location: Location::default(), source: Arc::new(error) }
+  }
+
+  pub fn budget_exhausted(progress: &str, steps: u64, elapsed: Duration) -> Error {
+    let what = format!("Budget exhausted after {} steps in {:.2?}: {}", steps, elapsed, progress);
+    Error::BudgetExhausted { what, steps, elapsed, location: Location::default() }
+  }
+
+  // Reports `token` as the culprit. If `token` is a slice of `line`, which
+  // is the case for anything that came out of split() or a regex capture,
+  // the column is taken from its position in the line.
+  pub fn token(line: &str, token: &str, what: &str) -> Error {
+    let start = line.as_ptr() as usize;
+    let offset = token.as_ptr() as usize;
+    let column = if start <= offset && offset + token.len() <= start + line.len() {
+      Some(offset - start)
+    } else {
+      line.find(token)
+    };
+
+    let location = Location { column: column.map(|c| line[..c].chars().count() + 1), ..Location::default() };
+    Error::Parse { what: what.to_string(), token: Some(token.to_string()), location, source: None }
+  }
+
+  pub fn location(&self) -> &Location {
+    match self {
+      Error::Parse { location, .. } => location,
+      Error::Solve { location, .. } => location,
+      Error::Io { location, .. } => location,
+      Error::BudgetExhausted { location, .. } => location,
+    }
+  }
+
+  fn location_mut(&mut self) -> &mut Location {
+    match self {
+      Error::Parse { location, .. } => location,
+      Error::Solve { location, .. } => location,
+      Error::Io { location, .. } => location,
+      Error::BudgetExhausted { location, .. } => location,
+    }
+  }
+
+  pub fn what(&self) -> &str {
+    match self {
+      Error::Parse { what, .. } => what,
+      Error::Solve { what, .. } => what,
+      Error::Io { what, .. } => what,
+      Error::BudgetExhausted { what, .. } => what,
+    }
+  }
+
+  pub fn in_day(mut self, day: u8) -> Self {
+    self.location_mut().day = Some(day);
+    self
+  }
+
+  // Errors from a multi line block (a bingo board, a scanner) carry a line
+  // number relative to the block. Calling this with the line the block
+  // starts on makes it absolute.
+  pub fn at_line(mut self, line: usize) -> Self {
+    let location = self.location_mut();
+    location.line = Some(line + location.line.map(|l| l - 1).unwrap_or(0));
+    self
+  }
+
+  pub fn with_source<E: StdError + Send + Sync + 'static>(mut self, error: E) -> Self {
+    if let Error::Parse { source, .. } = &mut self {
+      *source = Some(Arc::new(error));
+    }
+    self
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let location = self.location().to_string();
+    if !location.is_empty() {
+      write!(f, "{}: ", location)?;
+    }
+
+    write!(f, "{}", self.what())?;
+
+    if let Error::Parse { token: Some(token), .. } = self {
+      write!(f, " `{}`", token)?;
+    }
+
+    let mut source = self.source();
+    while let Some(s) = source {
+      write!(f, ": {}", s)?;
+      source = s.source();
+    }
+
+    Ok(())
+  }
+}
+
+impl StdError for Error {
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match self {
+      Error::Parse { source: Some(source), .. } => Some(source.as_ref()),
+      Error::Io { source, .. } => Some(source.as_ref()),
+      _ => None,
+    }
+  }
+}
+
+impl From<ParseIntError> for Error {
+  fn from(error: ParseIntError) -> Self {
+    Error::new("Unable to parse integer").with_source(error)
+  }
+}
+
+impl From<ParseFloatError> for Error {
+  fn from(error: ParseFloatError) -> Self {
+    Error::new("Unable to parse float").with_source(error)
+  }
+}
+
+impl From<serde_json::Error> for Error {
+  fn from(error: serde_json::Error) -> Self {
+    let location = Location { line: Some(error.line()), column: Some(error.column()), ..Location::default() };
+    Error::Parse { what: "Cannot parse JSON".to_string(), token: None, location, source: Some(Arc::new(error)) }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn token_column_from_slice() {
+    let line = "forward x";
+    let token = line.split(' ').nth(1).unwrap();
+    let error = Error::token(line, token, "Invalid length").at_line(3).in_day(2);
+
+    assert_eq!(Location { day: Some(2), line: Some(3), column: Some(9) }, *error.location());
+    assert_eq!("day 2, line 3, column 9: Invalid length `x`", error.to_string());
+  }
+
+  #[test]
+  fn block_lines_are_made_absolute() {
+    let error = Error::new("Invalid width").at_line(4).at_line(11);
+
+    assert_eq!(Some(14), error.location().line);
+  }
+
+  #[test]
+  fn source_chain_is_kept() {
+    let source = "1x".parse::<u32>().unwrap_err();
+    let error = Error::token("1x", "1x", "Unable to parse u32").with_source(source);
+
+    assert!(error.source().is_some());
+    assert_eq!("column 1: Unable to parse u32 `1x`: invalid digit found in string", error.to_string());
+  }
+}
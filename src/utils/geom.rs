@@ -0,0 +1,318 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+  pub x: isize,
+  pub y: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+  pub x: isize,
+  pub y: isize,
+  pub z: isize,
+}
+
+impl Point2 {
+  pub fn new(x: isize, y: isize) -> Self {
+    Point2 { x, y }
+  }
+
+  pub fn abs(self) -> Self {
+    self.map(isize::abs)
+  }
+
+  pub fn signum(self) -> Self {
+    self.map(isize::signum)
+  }
+
+  pub fn manhattan(self, other: Self) -> usize {
+    let d = (other - self).abs();
+    (d.x + d.y) as usize
+  }
+
+  pub fn chebyshev(self, other: Self) -> usize {
+    let d = (other - self).abs();
+    max(d.x, d.y) as usize
+  }
+
+  fn map<F: Fn(isize) -> isize>(self, f: F) -> Self {
+    Point2::new(f(self.x), f(self.y))
+  }
+
+  fn zip(self, other: Self, f: fn(isize, isize) -> isize) -> Self {
+    Point2::new(f(self.x, other.x), f(self.y, other.y))
+  }
+}
+
+impl Point3 {
+  pub fn new(x: isize, y: isize, z: isize) -> Self {
+    Point3 { x, y, z }
+  }
+
+  pub fn abs(self) -> Self {
+    self.map(isize::abs)
+  }
+
+  pub fn signum(self) -> Self {
+    self.map(isize::signum)
+  }
+
+  pub fn manhattan(self, other: Self) -> usize {
+    let d = (other - self).abs();
+    (d.x + d.y + d.z) as usize
+  }
+
+  pub fn chebyshev(self, other: Self) -> usize {
+    let d = (other - self).abs();
+    max(d.x, max(d.y, d.z)) as usize
+  }
+
+  fn map<F: Fn(isize) -> isize>(self, f: F) -> Self {
+    Point3::new(f(self.x), f(self.y), f(self.z))
+  }
+
+  fn zip(self, other: Self, f: fn(isize, isize) -> isize) -> Self {
+    Point3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+  }
+}
+
+impl From<(isize, isize)> for Point2 {
+  fn from(p: (isize, isize)) -> Self {
+    Point2::new(p.0, p.1)
+  }
+}
+
+impl From<[isize; 3]> for Point3 {
+  fn from(p: [isize; 3]) -> Self {
+    Point3::new(p[0], p[1], p[2])
+  }
+}
+
+// Access by axis, 0 is x, 1 is y and 2 is z.
+impl Index<usize> for Point3 {
+  type Output = isize;
+
+  fn index(&self, axis: usize) -> &isize {
+    match axis {
+      0 => &self.x,
+      1 => &self.y,
+      2 => &self.z,
+      _ => panic!("There is no axis {}", axis),
+    }
+  }
+}
+
+impl fmt::Display for Point2 {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{},{}", self.x, self.y)
+  }
+}
+
+impl fmt::Display for Point3 {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{},{},{}", self.x, self.y, self.z)
+  }
+}
+
+macro_rules! impl_ops {
+  ($point:ident) => {
+    impl Add for $point {
+      type Output = Self;
+
+      fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+      }
+    }
+
+    impl Sub for $point {
+      type Output = Self;
+
+      fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+      }
+    }
+
+    impl AddAssign for $point {
+      fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+      }
+    }
+
+    impl SubAssign for $point {
+      fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+      }
+    }
+
+    impl Mul<isize> for $point {
+      type Output = Self;
+
+      fn mul(self, factor: isize) -> Self {
+        self.map(|a| a * factor)
+      }
+    }
+
+    impl Neg for $point {
+      type Output = Self;
+
+      fn neg(self) -> Self {
+        self * -1
+      }
+    }
+  };
+}
+
+impl_ops!(Point2);
+impl_ops!(Point3);
+
+// Axis aligned boxes, both `min` and `max` are part of the box. A box with
+// any coordinate of `max` below the one of `min` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Box2 {
+  pub min: Point2,
+  pub max: Point2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Box3 {
+  pub min: Point3,
+  pub max: Point3,
+}
+
+// How many coordinates lie from `min` up to `max`.
+fn side(min: isize, max: isize) -> Option<u64> {
+  (max.abs_diff(min) as u64).checked_add(1)
+}
+
+impl Box2 {
+  pub fn new(min: Point2, max: Point2) -> Self {
+    Box2 { min, max }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.max.x < self.min.x || self.max.y < self.min.y
+  }
+
+  pub fn contains(&self, p: Point2) -> bool {
+    self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    let result = Box2::new(self.min.zip(other.min, max), self.max.zip(other.max, min));
+    if result.is_empty() { None } else { Some(result) }
+  }
+
+  pub fn intersects(&self, other: &Self) -> bool {
+    self.intersection(other).is_some()
+  }
+
+  // None if it does not fit in a u64.
+  pub fn area(&self) -> Option<u64> {
+    if self.is_empty() {
+      return Some(0);
+    }
+
+    side(self.min.x, self.max.x)?.checked_mul(side(self.min.y, self.max.y)?)
+  }
+
+  // The smallest box containing all of `points`, None if there are none.
+  pub fn bounding<I: IntoIterator<Item = Point2>>(points: I) -> Option<Self> {
+    points.into_iter().fold(None, |b: Option<Self>, p| match b {
+      Some(b) => Some(Box2::new(b.min.zip(p, min), b.max.zip(p, max))),
+      None => Some(Box2::new(p, p)),
+    })
+  }
+}
+
+impl Box3 {
+  pub fn new(min: Point3, max: Point3) -> Self {
+    Box3 { min, max }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.max.x < self.min.x || self.max.y < self.min.y || self.max.z < self.min.z
+  }
+
+  pub fn contains(&self, p: Point3) -> bool {
+    self.min.x <= p.x && p.x <= self.max.x &&
+    self.min.y <= p.y && p.y <= self.max.y &&
+    self.min.z <= p.z && p.z <= self.max.z
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    let result = Box3::new(self.min.zip(other.min, max), self.max.zip(other.max, min));
+    if result.is_empty() { None } else { Some(result) }
+  }
+
+  pub fn intersects(&self, other: &Self) -> bool {
+    self.intersection(other).is_some()
+  }
+
+  // None if it does not fit in a u64.
+  pub fn volume(&self) -> Option<u64> {
+    if self.is_empty() {
+      return Some(0);
+    }
+
+    side(self.min.x, self.max.x)?
+      .checked_mul(side(self.min.y, self.max.y)?)?
+      .checked_mul(side(self.min.z, self.max.z)?)
+  }
+
+  pub fn bounding<I: IntoIterator<Item = Point3>>(points: I) -> Option<Self> {
+    points.into_iter().fold(None, |b: Option<Self>, p| match b {
+      Some(b) => Some(Box3::new(b.min.zip(p, min), b.max.zip(p, max))),
+      None => Some(Box3::new(p, p)),
+    })
+  }
+}
+
+impl fmt::Display for Box3 {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "x={}..{},y={}..{},z={}..{}", self.min.x, self.max.x, self.min.y, self.max.y, self.min.z, self.max.z)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn point_arithmetic() {
+    let a = Point3::new(1, -2, 3);
+    let b = Point3::new(-4, 5, 6);
+
+    assert_eq!(Point3::new(-3, 3, 9), a + b);
+    assert_eq!(Point3::new(5, -7, -3), a - b);
+    assert_eq!(Point3::new(-2, 4, -6), -a * 2);
+    assert_eq!(15, a.manhattan(b));
+    assert_eq!(7, a.chebyshev(b));
+    assert_eq!(5, Point2::new(0, 0).manhattan(Point2::new(2, -3)));
+  }
+
+  #[test]
+  fn box_intersection() {
+    let a = Box3::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2));
+    let b = Box3::new(Point3::new(1, 1, 1), Point3::new(3, 3, 3));
+    let c = Box3::new(Point3::new(3, 3, 3), Point3::new(4, 4, 4));
+
+    assert_eq!(Some(27), a.volume());
+    assert_eq!(None, Box3::new(Point3::new(isize::MIN, 0, 0), Point3::new(isize::MAX, 0, 0)).volume());
+    assert_eq!(None, Box3::new(Point3::new(0, 0, 0), Point3::new(1 << 32, 1 << 32, 0)).volume());
+    assert_eq!(Some(6), Box2::new(Point2::new(-1, 0), Point2::new(1, 1)).area());
+    assert_eq!(Some(Box3::new(Point3::new(1, 1, 1), Point3::new(2, 2, 2))), a.intersection(&b));
+    assert!(!a.intersects(&c));
+    assert!(b.contains(Point3::new(3, 1, 2)));
+  }
+
+  #[test]
+  fn bounding_box() {
+    let points = vec![Point2::new(3, -1), Point2::new(-2, 4), Point2::new(0, 0)];
+
+    assert_eq!(Some(Box2::new(Point2::new(-2, -1), Point2::new(3, 4))), Box2::bounding(points));
+    assert_eq!(None, Box2::bounding(vec![]));
+  }
+}
@@ -0,0 +1,234 @@
+use std::cell::Cell;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use crate::utils::{parse_lines, Error};
+
+// Positions are (x, y), with (0, 0) being the top left corner.
+pub type Pos = (usize, usize);
+
+const DELTA4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const DELTA8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+  pub fn from_fn<F: FnMut(Pos) -> T>(width: usize, height: usize, mut f: F) -> Self {
+    let cells = (0..height)
+      .flat_map(|y| (0..width).map(move |x| (x, y)))
+      .map(&mut f)
+      .collect();
+
+    Grid { width, height, cells }
+  }
+
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+    let height = rows.len();
+    let width = rows.first().map_or(0, |r| r.len());
+
+    if let Some(y) = rows.iter().position(|r| r.len() != width) {
+      return Err(Error::new("All rows of a grid need the same width").at_line(y + 1));
+    }
+
+    let cells = rows.into_iter().flatten().collect();
+    Ok(Grid { width, height, cells })
+  }
+
+  // Parses a map of characters, one row per non-empty line, turning each
+  // character into a cell with `f`.
+  pub fn parse<F: Fn(char) -> Option<T>>(input: &str, f: F) -> Result<Self, Error> {
+    let width = Cell::new(None);
+    let rows = parse_lines(input, |line| {
+      let row = line
+        .char_indices()
+        .map(|(i, c)| f(c).ok_or_else(|| Error::token(line, &line[i..i + c.len_utf8()], "Invalid cell")))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+      match width.get() {
+        Some(w) if w != row.len() => Err(Error::token(line, line, &format!("Expected a row of width {}", w))),
+        _ => {
+          width.set(Some(row.len()));
+          Ok(row)
+        }
+      }
+    })?;
+
+    Grid::from_rows(rows)
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+    self.index_of(x, y).map(|i| &self.cells[i])
+  }
+
+  pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+    self.index_of(x, y).map(move |i| &mut self.cells[i])
+  }
+
+  // Like get, but positions outside of the grid wrap around to the
+  // other side.
+  pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+    let p = self.wrap(x, y);
+    &self[p]
+  }
+
+  pub fn positions(&self) -> impl Iterator<Item = Pos> {
+    let width = self.width;
+    (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+    self.positions().zip(self.cells.iter())
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    self.cells.chunks(self.width.max(1))
+  }
+
+  pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+    Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+  }
+
+  // The up to four orthogonal neighbours of `p` inside the grid.
+  pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    self.bounded(p, &DELTA4)
+  }
+
+  // The up to eight orthogonal and diagonal neighbours of `p` inside the grid.
+  pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    self.bounded(p, &DELTA8)
+  }
+
+  pub fn neighbours4_wrapping(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    self.wrapping(p, &DELTA4)
+  }
+
+  pub fn neighbours8_wrapping(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+    self.wrapping(p, &DELTA8)
+  }
+
+  fn index_of(&self, x: isize, y: isize) -> Option<usize> {
+    if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+      return None;
+    }
+
+    Some(y as usize * self.width + x as usize)
+  }
+
+  fn wrap(&self, x: isize, y: isize) -> Pos {
+    (x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)
+  }
+
+  fn bounded<'a>(&'a self, p: Pos, delta: &'a [(isize, isize)]) -> impl Iterator<Item = Pos> + 'a {
+    delta.iter().filter_map(move |(dx, dy)| {
+      let (x, y) = (p.0 as isize + dx, p.1 as isize + dy);
+      self.index_of(x, y).map(|_| (x as usize, y as usize))
+    })
+  }
+
+  fn wrapping<'a>(&'a self, p: Pos, delta: &'a [(isize, isize)]) -> impl Iterator<Item = Pos> + 'a {
+    delta.iter().map(move |(dx, dy)| self.wrap(p.0 as isize + dx, p.1 as isize + dy))
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  pub fn new(width: usize, height: usize, fill: T) -> Self {
+    Grid { width, height, cells: vec![fill; width * height] }
+  }
+}
+
+impl Grid<u8> {
+  // Parses maps of single digits like the height map of day 9.
+  pub fn parse_digits(input: &str) -> Result<Self, Error> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+  }
+}
+
+impl Grid<char> {
+  pub fn parse_chars(input: &str) -> Result<Self, Error> {
+    Grid::parse(input, Some)
+  }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, p: Pos) -> &T {
+    assert!(p.0 < self.width && p.1 < self.height, "{:?} is outside of the grid", p);
+    &self.cells[p.1 * self.width + p.0]
+  }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+  fn index_mut(&mut self, p: Pos) -> &mut T {
+    assert!(p.0 < self.width && p.1 < self.height, "{:?} is outside of the grid", p);
+    &mut self.cells[p.1 * self.width + p.0]
+  }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for row in self.rows() {
+      for c in row {
+        write!(f, "{}", c)?;
+      }
+      writeln!(f)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_and_display() -> Result<(), Error> {
+    let grid = Grid::parse_digits("123\n456\n")?;
+
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!(6, grid[(2, 1)]);
+    assert_eq!(Some(&4), grid.get(0, 1));
+    assert_eq!(None, grid.get(-1, 0));
+    assert_eq!("123\n456\n", grid.to_string());
+    Ok(())
+  }
+
+  #[test]
+  fn parse_errors() {
+    let error = Grid::parse_digits("123\n4x6\n").unwrap_err();
+    assert_eq!("line 2, column 2: Invalid cell `x`", error.to_string());
+
+    let error = Grid::parse_digits("123\n\n45\n").unwrap_err();
+    assert_eq!("line 3, column 1: Expected a row of width 3 `45`", error.to_string());
+  }
+
+  #[test]
+  fn bounded_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+
+    assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours4((0, 0)).collect::<Vec<_>>());
+    assert_eq!(3, grid.neighbours8((0, 0)).count());
+    assert_eq!(8, grid.neighbours8((1, 1)).count());
+  }
+
+  #[test]
+  fn wrapping_neighbours() {
+    let grid = Grid::new(3, 2, 0);
+
+    assert_eq!(vec![(0, 1), (2, 0), (1, 0), (0, 1)], grid.neighbours4_wrapping((0, 0)).collect::<Vec<_>>());
+    assert_eq!(&0, grid.get_wrapping(-1, 5));
+  }
+}
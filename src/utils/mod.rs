@@ -1,18 +1,12 @@
-use std::num::{ParseIntError, ParseFloatError};
-use std::fmt;
-use std::fs::File;
-use std::io::prelude::*;
+use std::str::FromStr;
 
-#[allow(dead_code)]
-pub fn read_file(filename: &str) -> String {
-  let mut f = File::open(filename).expect("file not found");
+mod error;
+mod geom;
+mod grid;
 
-  let mut contents = String::new();
-  f.read_to_string(&mut contents)
-    .expect("something went wrong reading the file");
-
-  contents
-}
+pub use error::{Error, Location};
+pub use geom::{Box2, Box3, Point2, Point3};
+pub use grid::{Grid, Pos};
 
 pub fn preprocess_input(input: &str) -> Vec<&str> {
   input
@@ -21,67 +15,53 @@ pub fn preprocess_input(input: &str) -> Vec<&str> {
     .collect()
 }
 
-#[derive(Debug)]
-pub struct Error {
-  pub what: String
-}
-
-impl Error {
-  pub fn new(s: &str) -> Error {
-    let what = s.to_string();
-    Error { what }
-  }
-}
-
-impl fmt::Display for Error {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}", self.what)
-  }
-}
-
-
-#[derive(Debug, Clone)]
-pub struct ParseError {
-  pub what: String
+// Parses every non-empty line with `f`, errors are tagged with the line
+// they occurred in.
+pub fn parse_lines<T, F>(input: &str, f: F) -> Result<Vec<T>, Error>
+where
+  F: Fn(&str) -> Result<T, Error>,
+{
+  input
+    .lines()
+    .enumerate()
+    .filter(|(_, l)| !l.is_empty())
+    .map(|(i, l)| f(l).map_err(|e| e.at_line(i + 1)))
+    .collect()
 }
 
-impl ParseError {
-  pub fn new(s: &str) -> ParseError {
-    let what = s.to_string();
-    ParseError { what }
-  }
+// Parses a single token of `line`, reporting the token and its column if
+// it is not a valid `T`.
+pub fn parse_token<T>(line: &str, token: &str) -> Result<T, Error>
+where
+  T: FromStr,
+  T::Err: std::error::Error + Send + Sync + 'static,
+{
+  token
+    .parse::<T>()
+    .map_err(|e| Error::token(line, token, &format!("Unable to parse {}", std::any::type_name::<T>())).with_source(e))
 }
 
-impl fmt::Display for ParseError {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}", self.what)
-  }
+// Parses a line of single digits like "2199943210".
+pub fn parse_digits(line: &str) -> Result<Vec<u8>, Error> {
+  line
+    .char_indices()
+    .map(|(i, c)| c
+      .to_digit(10)
+      .map(|d| d as u8)
+      .ok_or_else(|| Error::token(line, &line[i..i + c.len_utf8()], "Invalid digit")))
+    .collect()
 }
 
-impl std::error::Error for ParseError {
-  fn description(&self) -> &str {
-    "Error while parsing input"
-  }
-
-  fn cause(&self) -> Option<&dyn std::error::Error> {
-    None
-  }
-}
+// Splits the input into blocks separated by an empty line, together with
+// the line number each block starts on.
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+  let mut line = 1;
+  let mut result = vec![];
 
-impl From<ParseIntError> for ParseError {
-  fn from(_error: ParseIntError) -> Self {
-    ParseError::new("Unable to parse integer")
+  for block in input.split("\n\n") {
+    result.push((line, block));
+    line += block.matches('\n').count() + 2;
   }
-}
 
-impl From<ParseFloatError> for ParseError {
-  fn from(_error: ParseFloatError) -> Self {
-    ParseError::new("Unable to parse float")
-  }
+  result
 }
-
-impl From<ParseError> for Error {
-  fn from(_error: ParseError) -> Self {
-    Error::new("Unable to parse something")
-  }
-}
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+use crate::utils::{parse_token, Error};
+
+// Windows `start` up to `start + length` each lie deeper than the one
+// before them. Windows are numbered by their first depth, from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+// Window `at` lies `by` deeper than the window before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump {
+    pub at: usize,
+    pub by: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Analysis {
+    pub windows: usize,
+    pub increases: usize,
+    pub longest_run: Option<Run>,
+    pub largest_jump: Option<Jump>,
+}
+
+// Compares the sums of sliding windows of depths as they come in, only
+// ever keeping the last `window` of them.
+#[derive(Debug, Clone)]
+pub struct DepthAnalyser {
+    window: usize,
+    recent: VecDeque<u32>,
+    sum: u64,
+    previous: Option<u64>,
+    run: Option<Run>,
+    analysis: Analysis,
+}
+
+impl DepthAnalyser {
+    pub fn new(window: usize) -> Result<Self, Error> {
+        if window == 0 {
+            return Err(Error::new("A window needs at least one depth"));
+        }
+
+        Ok(DepthAnalyser {
+            window,
+            // Only grows with the depths that come in, the window may be
+            // far larger than there are depths.
+            recent: VecDeque::new(),
+            sum: 0,
+            previous: None,
+            run: None,
+            analysis: Analysis::default(),
+        })
+    }
+
+    pub fn push(&mut self, depth: u32) {
+        self.recent.push_back(depth);
+        self.sum += depth as u64;
+
+        if self.recent.len() > self.window {
+            self.sum -= self.recent.pop_front().unwrap_or_default() as u64;
+        }
+        if self.recent.len() < self.window {
+            return;
+        }
+
+        let at = self.analysis.windows;
+        self.analysis.windows += 1;
+
+        match self.previous {
+            Some(previous) if self.sum > previous => {
+                let by = self.sum - previous;
+                let run = match self.run {
+                    Some(r) => Run { length: r.length + 1, ..r },
+                    None => Run { start: at - 1, length: 1 },
+                };
+
+                self.analysis.increases += 1;
+                if self.analysis.longest_run.is_none_or(|r| run.length > r.length) {
+                    self.analysis.longest_run = Some(run);
+                }
+                if self.analysis.largest_jump.is_none_or(|j| by > j.by) {
+                    self.analysis.largest_jump = Some(Jump { at, by });
+                }
+                self.run = Some(run);
+            },
+            _ => self.run = None,
+        }
+
+        self.previous = Some(self.sum);
+    }
+
+    pub fn analysis(&self) -> &Analysis {
+        &self.analysis
+    }
+}
+
+pub fn analyse<I: IntoIterator<Item = u32>>(depths: I, window: usize) -> Result<Analysis, Error> {
+    let mut analyser = DepthAnalyser::new(window)?;
+    depths.into_iter().for_each(|d| analyser.push(d));
+
+    Ok(analyser.analysis)
+}
+
+// Reads one depth per line, from stdin for example, however many there are.
+pub fn analyse_reader<R: BufRead>(reader: R, window: usize) -> Result<Analysis, Error> {
+    let mut analyser = DepthAnalyser::new(window)?;
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| Error::io("Cannot read depths", e))?;
+        if !line.is_empty() {
+            analyser.push(parse_token(&line, &line).map_err(|e| e.at_line(i + 1))?);
+        }
+    }
+
+    Ok(analyser.analysis)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: [u32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn single_depths() -> Result<(), Error> {
+        let analysis = analyse(SAMPLE, 1)?;
+
+        assert_eq!(7, analysis.increases);
+        assert_eq!(Some(Run { start: 0, length: 3 }), analysis.longest_run);
+        Ok(assert_eq!(Some(Jump { at: 6, by: 33 }), analysis.largest_jump))
+    }
+
+    #[test]
+    fn sliding_windows() -> Result<(), Error> {
+        let analysis = analyse(SAMPLE, 3)?;
+
+        assert_eq!(8, analysis.windows);
+        assert_eq!(5, analysis.increases);
+        assert_eq!(Some(Run { start: 3, length: 4 }), analysis.longest_run);
+        assert_eq!(Some(Jump { at: 5, by: 69 }), analysis.largest_jump);
+        assert_eq!(Analysis::default(), analyse(SAMPLE, usize::MAX)?);
+        Ok(assert_eq!(Analysis::default(), analyse(SAMPLE, 11)?))
+    }
+
+    #[test]
+    fn read_lines() -> Result<(), Error> {
+        let analysis = analyse_reader("1\n2\n\n3\n".as_bytes(), 2)?;
+        let error = analyse_reader("1\n2\nx\n".as_bytes(), 2).unwrap_err();
+
+        assert_eq!(1, analysis.increases);
+        assert_eq!("line 3, column 1: Unable to parse u32 `x`: invalid digit found in string", error.to_string());
+        Ok(assert!(DepthAnalyser::new(0).is_err()))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::utils::{parse_lines, parse_token, Error};
+use crate::solution::{Answer, Solution};
+
+pub mod analyser;
+
+#[aoc_generator(day1)]
+pub fn input_generator(input: &str) -> Result<Vec<u32>, Error> {
+    parse_lines(input, |s| parse_token(s, s))
+}
+
+#[aoc(day1, part1)]
+pub fn solve_part1(input: &[u32]) -> Result<usize, Error> {
+    Ok(analyser::analyse(input.iter().copied(), 1)?.increases)
+}
+
+#[aoc(day1, part2)]
+pub fn solve_part2(input: &[u32]) -> Result<usize, Error> {
+    Ok(analyser::analyse(input.iter().copied(), 3)?.increases)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::utils::Error;
+use super::{Command, Direction};
+
+// How the submarine reads up and down: as moving the submarine itself, or
+// as tilting it, so that only going forward changes the depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Direct,
+    Aim,
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "direct" => Ok(Mode::Direct),
+            "aim" => Ok(Mode::Aim),
+            _ => Err(Error::token(s, s, "Expected direct or aim")),
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mode::Direct => write!(f, "direct"),
+            Mode::Aim => write!(f, "aim"),
+        }
+    }
+}
+
+// What the submarine is allowed to do and how it reads up and down. The
+// two parts of the puzzle only know forward, up and down, the other rules
+// take every manoeuvre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub mode: Mode,
+    pub manoeuvres: bool,
+}
+
+impl Rules {
+    pub const PART1: Rules = Rules { mode: Mode::Direct, manoeuvres: false };
+    pub const PART2: Rules = Rules { mode: Mode::Aim, manoeuvres: false };
+
+    pub fn new(mode: Mode) -> Self {
+        Rules { mode, manoeuvres: true }
+    }
+}
+
+impl FromStr for Rules {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part1" => Ok(Rules::PART1),
+            "part2" => Ok(Rules::PART2),
+            _ => Mode::from_str(s)
+                .map(Rules::new)
+                .map_err(|_| Error::token(s, s, "Expected part1, part2, direct or aim")),
+        }
+    }
+}
+
+// Where the bow points. The submarine starts out facing east, so going
+// forward without turning adds to its horizontal position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Heading {
+    #[default]
+    East,
+    South,
+    West,
+    North,
+}
+
+impl Heading {
+    const CLOCKWISE: [Heading; 4] = [Heading::East, Heading::South, Heading::West, Heading::North];
+
+    pub fn turned(&self, degrees: i64) -> Heading {
+        let at = Heading::CLOCKWISE.iter().position(|h| h == self).unwrap_or_default() as i64;
+        Heading::CLOCKWISE[(at + degrees / 90).rem_euclid(4) as usize]
+    }
+
+    // One step ahead, as (horizontal, lateral) with north lateral.
+    fn ahead(&self) -> (i64, i64) {
+        match self {
+            Heading::East => (1, 0),
+            Heading::South => (0, -1),
+            Heading::West => (-1, 0),
+            Heading::North => (0, 1),
+        }
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Heading::East => "east",
+            Heading::South => "south",
+            Heading::West => "west",
+            Heading::North => "north",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub lateral: i64,
+    pub depth: i64,
+    pub aim: i64,
+    pub heading: Heading,
+}
+
+impl Position {
+    fn moved(&self, (dx, dy): (i64, i64), len: i64) -> Option<Position> {
+        Some(Position {
+            horizontal: self.horizontal.checked_add(dx.checked_mul(len)?)?,
+            lateral: self.lateral.checked_add(dy.checked_mul(len)?)?,
+            ..*self
+        })
+    }
+
+    // Going forward in aim mode dives as deep as the aim says, going back
+    // undoes that.
+    fn sailed(&self, len: i64, mode: Mode) -> Option<Position> {
+        let moved = self.moved(self.heading.ahead(), len)?;
+
+        Some(match mode {
+            Mode::Direct => moved,
+            Mode::Aim => Position { depth: self.depth.checked_add(self.aim.checked_mul(len)?)?, ..moved },
+        })
+    }
+
+    // None when the submarine goes further than an i64 can tell.
+    pub fn apply(&self, command: &Command, mode: Mode) -> Option<Position> {
+        let len = command.len;
+        let (dx, dy) = self.heading.ahead();
+
+        match (mode, command.dir) {
+            (_, Direction::Forward) => self.sailed(len, mode),
+            (_, Direction::Back) => self.sailed(len.checked_neg()?, mode),
+            (_, Direction::Left) => self.moved((-dy, dx), len),
+            (_, Direction::Right) => self.moved((dy, -dx), len),
+            (_, Direction::Turn) => Some(Position { heading: self.heading.turned(len), ..*self }),
+            (Mode::Direct, Direction::Up) => Some(Position { depth: self.depth.checked_sub(len)?, ..*self }),
+            (Mode::Direct, Direction::Down) => Some(Position { depth: self.depth.checked_add(len)?, ..*self }),
+            (Mode::Aim, Direction::Up) => Some(Position { aim: self.aim.checked_sub(len)?, ..*self }),
+            (Mode::Aim, Direction::Down) => Some(Position { aim: self.aim.checked_add(len)?, ..*self }),
+        }
+    }
+
+    pub fn product(&self) -> i128 {
+        self.horizontal as i128 * self.depth as i128
+    }
+}
+
+// Every position of the submarine, from where it starts to where the last
+// command leaves it.
+pub fn course(commands: &[Command], rules: Rules) -> Result<Vec<Position>, Error> {
+    let mut positions = vec![Position::default()];
+
+    for (i, command) in commands.iter().enumerate() {
+        if !rules.manoeuvres && command.dir.is_manoeuvre() {
+            return Err(Error::solve(&format!("Command {}, `{}`, is not part of the puzzle", i + 1, command)));
+        }
+
+        let position = positions[i]
+            .apply(command, rules.mode)
+            .ok_or_else(|| Error::solve(&format!("The submarine is out of range after command {}, `{}`", i + 1, command)))?;
+        positions.push(position);
+    }
+
+    Ok(positions)
+}
+
+pub fn travel(commands: &[Command], rules: Rules) -> Result<Position, Error> {
+    Ok(course(commands, rules)?.last().copied().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::y2021::day02::input_generator;
+
+    const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+    #[test]
+    fn trajectories() -> Result<(), Error> {
+        let commands = input_generator(SAMPLE)?;
+        let direct = course(&commands, Rules::PART1)?;
+        let aim = course(&commands, Rules::PART2)?;
+
+        assert_eq!(7, direct.len());
+        assert_eq!(Position { horizontal: 13, depth: 5, ..Position::default() }, direct[3]);
+        assert_eq!(Position { horizontal: 13, depth: 40, aim: 5, ..Position::default() }, aim[3]);
+        assert_eq!(150, travel(&commands, Rules::new(Mode::Direct))?.product());
+        Ok(assert_eq!(900, travel(&commands, Rules::PART2)?.product()))
+    }
+
+    #[test]
+    fn long_commands() -> Result<(), Error> {
+        let commands = input_generator("forward 12\ndown 345\nforward 1000")?;
+
+        assert_eq!(Position { horizontal: 1012, depth: 345, ..Position::default() }, travel(&commands, Rules::PART1)?);
+        assert_eq!(Position { horizontal: 1012, depth: 345000, aim: 345, ..Position::default() }, travel(&commands, Rules::PART2)?);
+        Ok(assert!(travel(&input_generator("down 9223372036854775807\ndown 1")?, Rules::PART1).is_err()))
+    }
+
+    #[test]
+    fn manoeuvres() -> Result<(), Error> {
+        let commands = input_generator("forward 10\nturn 90\nforward 4\nleft 3\ndown 2\nturn -180\nback 5\nright 1")?;
+        let end = Position { horizontal: 14, lateral: -9, depth: -10, aim: 2, heading: Heading::North };
+
+        assert_eq!(end, travel(&commands, Rules::new(Mode::Aim))?);
+        assert_eq!(Position { depth: 2, aim: 0, ..end }, travel(&commands, Rules::new(Mode::Direct))?);
+        assert_eq!(Heading::West, Heading::East.turned(-540));
+        let error = travel(&input_generator("forward 1\n\nturn 90")?, Rules::PART2).unwrap_err();
+        assert_eq!("Command 2, `turn 90`, is not part of the puzzle", error.to_string());
+        assert!(input_generator("turn 45").is_err());
+        Ok(assert_eq!(Rules::new(Mode::Aim), "aim".parse::<Rules>()?))
+    }
+}
@@ -0,0 +1,129 @@
+use crate::utils::{parse_lines, parse_token, Error};
+use std::fmt;
+use std::str::FromStr;
+use regex::Regex;
+use crate::solution::{Answer, Solution};
+
+pub mod course;
+
+use course::Rules;
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Forward,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Turn,
+}
+
+impl Direction {
+    // Everything beyond what the submarine of the puzzle can do.
+    fn is_manoeuvre(&self) -> bool {
+        !matches!(self, Direction::Forward | Direction::Up | Direction::Down)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "forward" => Ok(Direction::Forward),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            "back" => Ok(Direction::Back),
+            "left" => Ok(Direction::Left),
+            "right" => Ok(Direction::Right),
+            "turn" => Ok(Direction::Turn),
+            _ => Err(Error::token(s, s, "Invalid direction")),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Direction::Forward => "forward",
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Back => "back",
+            Direction::Left => "left",
+            Direction::Right => "right",
+            Direction::Turn => "turn",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Command {
+    dir: Direction,
+    len: i64,
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.dir, self.len)
+    }
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static!{
+            static ref RE: Regex = Regex::new(r"^(?P<dir>.*)? (?P<len>-?\d+)$").unwrap();
+        }
+
+        let cap = RE.captures(s).ok_or_else(|| Error::token(s, s, "Expected <direction> <length>"))?;
+
+        let dir = cap.name("dir").map_or("", |v| v.as_str());
+        let dir = Direction::from_str(&dir.to_lowercase()).map_err(|_| Error::token(s, dir, "Invalid direction"))?;
+        let digits = cap.name("len").map_or("", |v| v.as_str());
+        let len = parse_token::<i64>(s, digits)?;
+
+        // Turns are in degrees, clockwise, a quarter turn at a time.
+        if matches!(dir, Direction::Turn) && len % 90 != 0 {
+            return Err(Error::token(s, digits, "Turns go in steps of 90 degrees"));
+        }
+
+        Ok(Self { dir, len })
+    }
+}
+
+#[aoc_generator(day2)]
+pub fn input_generator(input: &str) -> Result<Vec<Command>, Error> {
+    parse_lines(input, Command::from_str)
+}
+
+#[aoc(day2, part1)]
+pub fn solve_part1(input: &[Command]) -> Result<i128, Error> {
+    Ok(course::travel(input, Rules::PART1)?.product())
+}
+
+#[aoc(day2, part2)]
+pub fn solve_part2(input: &[Command]) -> Result<i128, Error> {
+    Ok(course::travel(input, Rules::PART2)?.product())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::y2021::day03::report::Report;
+use crate::y2021::day03::reading::Reading;
+use crate::utils::{parse_lines, Error};
+use std::cell::Cell;
+use std::str::FromStr;
+use crate::solution::{Answer, Solution};
+
+pub mod reading;
+pub mod report;
+
+#[aoc_generator(day3)]
+pub fn input_generator(input: &str) -> Result<Report, Error> {
+    // Checked here as well as in the report, only here are the lines known.
+    let width = Cell::new(None);
+    let readings: Vec<Reading> = parse_lines(input, |s| {
+        let reading = Reading::from_str(s)?;
+        let first = width.get().unwrap_or(reading.width());
+        if reading.width() != first {
+            return Err(Error::new(&format!("The reading is {} bits wide, the first one {}", reading.width(), first)));
+        }
+
+        width.set(Some(first));
+        Ok(reading)
+    })?;
+
+    Report::new(readings)
+}
+
+#[aoc(day3, part1)]
+pub fn solve_part1(report: &Report) -> Result<u128, Error> {
+    Ok(report.gamma()? as u128 * report.epsilon()? as u128)
+}
+
+#[aoc(day3, part2)]
+pub fn solve_part2(report: &Report) -> Result<u128, Error> {
+    let oxygen = report.oxygen()?;
+    let co2scrub = report.co2_scrubber()?;
+
+    Ok(oxygen as u128 * co2scrub as u128)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
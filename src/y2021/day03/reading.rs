@@ -0,0 +1,39 @@
+use crate::utils::Error;
+use core::str::FromStr;
+
+pub const MAX_WIDTH: usize = 64;
+
+// A reading packed into the low `width` bits of an integer. Positions are
+// counted from the left, the way the report is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reading {
+    bits: u64,
+    width: usize,
+}
+
+impl FromStr for Reading {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(c) = s.matches(|c| c != '0' && c != '1').next() {
+            return Err(Error::token(s, c, "Expected a binary number"));
+        }
+        if s.len() > MAX_WIDTH {
+            return Err(Error::token(s, s, &format!("Readings are at most {} bits wide", MAX_WIDTH)));
+        }
+
+        let bits = s.bytes().fold(0, |acc, b| acc << 1 | (b - b'0') as u64);
+
+        Ok(Self { bits, width: s.len() })
+    }
+}
+
+impl Reading {
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
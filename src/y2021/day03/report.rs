@@ -0,0 +1,187 @@
+use crate::y2021::day03::Reading;
+use std::cmp::Ordering;
+use crate::utils::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Common {
+    Most,
+    Least,
+}
+
+// What to pick when a column has as many 0s as 1s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tie {
+    Zero,
+    One,
+    Fail,
+}
+
+// How a bit is picked from a column of the report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Criterion {
+    pub common: Common,
+    pub tie: Tie,
+}
+
+impl Criterion {
+    pub const GAMMA: Criterion = Criterion { common: Common::Most, tie: Tie::One };
+    pub const EPSILON: Criterion = Criterion { common: Common::Least, tie: Tie::Zero };
+    pub const OXYGEN: Criterion = Criterion { common: Common::Most, tie: Tie::One };
+    pub const CO2_SCRUBBER: Criterion = Criterion { common: Common::Least, tie: Tie::Zero };
+
+    pub fn new(common: Common, tie: Tie) -> Self {
+        Criterion { common, tie }
+    }
+}
+
+// All readings share one width, so each is just its bits and a column is
+// a shift away. Every filter pass only looks at the readings still in the
+// running, which keeps the ratings at O(n·w) however long the report is.
+#[derive(Clone, Debug)]
+pub struct Report {
+    readings: Vec<u64>,
+    width: usize,
+}
+
+impl Report {
+    pub fn new(readings: Vec<Reading>) -> Result<Self, Error> {
+        let width = readings.first().map(|r| r.width()).ok_or(Error::new("The report has no readings"))?;
+
+        if let Some((i, r)) = readings.iter().enumerate().find(|(_, r)| r.width() != width) {
+            return Err(Error::new(&format!("Reading {} is {} bits wide, the first one {}", i + 1, r.width(), width)));
+        }
+
+        Ok(Self { readings: readings.iter().map(|r| r.bits()).collect(), width })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn bit(&self, reading: u64, pos: usize) -> bool {
+        reading >> (self.width - 1 - pos) & 1 == 1
+    }
+
+    fn ones(&self, readings: &[u64], pos: usize) -> usize {
+        readings.iter().filter(|r| self.bit(**r, pos)).count()
+    }
+
+    // The bit `criterion` picks at `pos` among `readings`.
+    fn select(&self, readings: &[u64], pos: usize, criterion: Criterion) -> Result<bool, Error> {
+        let ones = self.ones(readings, pos);
+        let zeros = readings.len() - ones;
+
+        Ok(match ones.cmp(&zeros) {
+            Ordering::Greater => criterion.common == Common::Most,
+            Ordering::Less => criterion.common == Common::Least,
+            Ordering::Equal => match criterion.tie {
+                Tie::Zero => false,
+                Tie::One => true,
+                Tie::Fail => return Err(Error::solve(&format!("Bit {} is tied, {} readings have a 0 and {} a 1", pos, zeros, ones))),
+            },
+        })
+    }
+
+    // Picks bit `pos` by `criteria[pos]` over the whole report, the last
+    // criterion holds for all columns after it like it does for ratings.
+    pub fn pattern(&self, criteria: &[Criterion]) -> Result<u64, Error> {
+        let last = criteria.last().ok_or(Error::new("A pattern needs at least one criterion"))?;
+
+        (0..self.width).try_fold(0, |acc, pos| {
+            Ok(acc << 1 | self.select(&self.readings, pos, *criteria.get(pos).unwrap_or(last))? as u64)
+        })
+    }
+
+    pub fn gamma(&self) -> Result<u64, Error> {
+        self.pattern(&[Criterion::GAMMA])
+    }
+
+    pub fn epsilon(&self) -> Result<u64, Error> {
+        self.pattern(&[Criterion::EPSILON])
+    }
+
+    // Keeps the readings with the bit `criteria[pos]` picks, one column
+    // after the other until a single reading is left. The last criterion
+    // holds for all columns after it, so a single one holds for all.
+    pub fn rating(&self, criteria: &[Criterion]) -> Result<u64, Error> {
+        let last = criteria.last().ok_or(Error::new("A rating needs at least one criterion"))?;
+        let mut candidates = self.readings.clone();
+
+        for pos in 0..self.width {
+            if candidates.len() <= 1 {
+                break;
+            }
+
+            let keep = self.select(&candidates, pos, *criteria.get(pos).unwrap_or(last))?;
+            candidates.retain(|r| self.bit(*r, pos) == keep);
+
+            if candidates.is_empty() {
+                return Err(Error::solve(&format!("No readings are left after bit {}", pos)));
+            }
+        }
+
+        candidates.first().copied().ok_or(Error::solve("The report has no readings"))
+    }
+
+    pub fn oxygen(&self) -> Result<u64, Error> {
+        self.rating(&[Criterion::OXYGEN])
+    }
+
+    pub fn co2_scrubber(&self) -> Result<u64, Error> {
+        self.rating(&[Criterion::CO2_SCRUBBER])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::y2021::day03::input_generator;
+
+    #[test]
+    fn sample() -> Result<(), Error> {
+        let report = input_generator("00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010")?;
+
+        assert_eq!((22, 9), (report.gamma()?, report.epsilon()?));
+        Ok(assert_eq!((23, 10), (report.oxygen()?, report.co2_scrubber()?)))
+    }
+
+    #[test]
+    fn wide_readings() -> Result<(), Error> {
+        let report = input_generator(&format!("{}\n{}0\n{}", "1".repeat(64), "1".repeat(63), "0".repeat(64)))?;
+
+        assert_eq!((u64::MAX - 1, 1), (report.gamma()?, report.epsilon()?));
+        assert_eq!((u64::MAX, 0), (report.oxygen()?, report.co2_scrubber()?));
+        assert!(input_generator(&"1".repeat(65)).is_err());
+        assert_eq!("line 2: The reading is 4 bits wide, the first one 3", input_generator("101\n1010").unwrap_err().to_string());
+        assert_eq!("line 4: The reading is 4 bits wide, the first one 3", input_generator("\n101\n\n1010").unwrap_err().to_string());
+        assert_eq!("Reading 2 is 4 bits wide, the first one 3", Report::new(vec!["101".parse()?, "1010".parse()?]).unwrap_err().to_string());
+        Ok(assert!(input_generator("10\n12").is_err()))
+    }
+
+    // Every 20 bit number once, so each column is a tie and goes to 1.
+    #[test]
+    fn many_readings() -> Result<(), Error> {
+        let readings = (0..1 << 20).map(|i| format!("{:020b}", i).parse()).collect::<Result<Vec<Reading>, _>>()?;
+        let report = Report::new(readings)?;
+
+        assert_eq!((0xfffff, 0), (report.gamma()?, report.epsilon()?));
+        Ok(assert_eq!((0xfffff, 0), (report.oxygen()?, report.co2_scrubber()?)))
+    }
+
+    #[test]
+    fn custom_ratings() -> Result<(), Error> {
+        let report = input_generator("000\n011\n101\n110")?;
+        let most = Criterion::new(Common::Most, Tie::Zero);
+        let least = Criterion::new(Common::Least, Tie::One);
+
+        assert_eq!(0b011, report.rating(&[most, least])?);
+        assert_eq!(0b101, report.rating(&[least, most, least])?);
+        assert_eq!(0b000, report.pattern(&[most])?);
+        assert_eq!(0b011, report.pattern(&[most, least])?);
+        assert_eq!(0b101, report.pattern(&[least, most, least])?);
+        assert_eq!("Bit 0 is tied, 2 readings have a 0 and 2 a 1", report.pattern(&[Criterion::new(Common::Most, Tie::Fail)]).unwrap_err().to_string());
+        assert!(report.pattern(&[]).is_err());
+        assert!(report.rating(&[]).is_err());
+        Ok(assert!(input_generator("00\n01")?.rating(&[Criterion::new(Common::Least, Tie::One)]).is_err()))
+    }
+}
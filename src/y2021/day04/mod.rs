@@ -0,0 +1,258 @@
+use crate::utils::{blocks, parse_lines, parse_token, Error};
+use std::str::FromStr;
+use crate::solution::{Answer, Solution};
+
+// What a board needs marked to win. The puzzle only counts full rows and
+// columns, diagonals are both of them, from corner to corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    Row,
+    Column,
+    Diagonal,
+    Corners,
+    Blackout,
+}
+
+impl Pattern {
+    pub const PUZZLE: &'static [Pattern] = &[Pattern::Row, Pattern::Column];
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rows" => Ok(Pattern::Row),
+            "columns" => Ok(Pattern::Column),
+            "diagonals" => Ok(Pattern::Diagonal),
+            "corners" => Ok(Pattern::Corners),
+            "blackout" => Ok(Pattern::Blackout),
+            _ => Err(Error::token(s, s, "Expected rows, columns, diagonals, corners or blackout")),
+        }
+    }
+}
+
+// A square board of any size.
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    size: usize,
+    numbers: Vec<Vec<u32>>,
+    marked: Vec<Vec<bool>>,
+}
+
+impl FromStr for BingoBoard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = parse_lines(s, |l| {
+            l.split(' ')
+                .filter(|v| !v.is_empty())
+                .map(|w| parse_token::<u32>(l, w))
+                .collect::<Result<Vec<_>, Error>>()
+        })?;
+
+        let size = numbers.len();
+        if size == 0 {
+            return Err(Error::new("Empty board"));
+        }
+        if let Some((i, row)) = numbers.iter().enumerate().find(|(_, r)| r.len() != size) {
+            return Err(Error::new(&format!("Row {} is {} numbers wide, the board is {} rows high", i + 1, row.len(), size)));
+        }
+
+        let marked = vec![vec![false; size]; size];
+
+        Ok(Self { size, numbers, marked })
+    }
+}
+
+impl BingoBoard {
+    fn mark(&mut self, n: u32) {
+        for (row, marked) in self.numbers.iter().zip(self.marked.iter_mut()) {
+            for (v, m) in row.iter().zip(marked.iter_mut()) {
+                if *v == n {
+                    *m = true;
+                }
+            }
+        }
+    }
+
+    fn wins(&self, pattern: Pattern) -> bool {
+        let n = self.size;
+        let m = |i: usize, j: usize| self.marked[i][j];
+
+        match pattern {
+            Pattern::Row => (0..n).any(|i| (0..n).all(|j| m(i, j))),
+            Pattern::Column => (0..n).any(|j| (0..n).all(|i| m(i, j))),
+            Pattern::Diagonal => (0..n).all(|i| m(i, i)) || (0..n).all(|i| m(i, n - 1 - i)),
+            Pattern::Corners => m(0, 0) && m(0, n - 1) && m(n - 1, 0) && m(n - 1, n - 1),
+            Pattern::Blackout => self.marked.iter().flatten().all(|m| *m),
+        }
+    }
+
+    fn done(&self, patterns: &[Pattern]) -> bool {
+        patterns.iter().any(|p| self.wins(*p))
+    }
+
+    fn unmarked(&self) -> u64 {
+        self.numbers
+            .iter()
+            .flatten()
+            .zip(self.marked.iter().flatten())
+            .filter(|(_, m)| !**m)
+            .map(|(v, _)| *v as u64)
+            .sum()
+    }
+}
+
+// A board that won, with the number it won on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub number: u32,
+    pub score: u64,
+}
+
+#[derive(Debug)]
+pub struct BingoGame {
+    numbers: Vec<u32>,
+    boards: Vec<BingoBoard>,
+}
+
+impl FromStr for BingoGame {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let game = blocks(s)
+            .into_iter()
+            .filter(|(_, v)| *v != "")
+            .collect::<Vec<_>>();
+
+        let (line, numbers) = game.first().ok_or(Error::new("No numbers to draw"))?;
+        let numbers = numbers
+            .split(",")
+            .map(|v| parse_token::<u32>(numbers, v).map_err(|e| e.at_line(*line)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let boards = game
+            .iter()
+            .skip(1)
+            .map(|(line, v)| BingoBoard::from_str(v).map_err(|e| e.at_line(*line)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self { numbers, boards })
+    }
+}
+
+impl BingoGame {
+    // Every board that wins by any of `patterns`, in the order they win.
+    // A board only wins once, it is out of the game after that.
+    pub fn play(&self, patterns: &[Pattern]) -> Vec<Win> {
+        let mut boards = self.boards.clone();
+        let mut playing = (0..boards.len()).collect::<Vec<_>>();
+        let mut wins = vec![];
+
+        for n in &self.numbers {
+            playing.retain(|i| {
+                let board = &mut boards[*i];
+                board.mark(*n);
+
+                if board.done(patterns) {
+                    wins.push(Win { board: *i, number: *n, score: board.unmarked() * *n as u64 });
+                    return false;
+                }
+                true
+            });
+
+            if playing.is_empty() {
+                break;
+            }
+        }
+
+        wins
+    }
+}
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Result<BingoGame, Error> {
+    BingoGame::from_str(input)
+}
+
+#[aoc(day4, part1)]
+pub fn solve_part1(input: &BingoGame) -> Result<u64, Error> {
+    let wins = input.play(Pattern::PUZZLE);
+
+    wins.first().map(|w| w.score).ok_or(Error::solve("No board wins with the numbers drawn"))
+}
+
+#[aoc(day4, part2)]
+pub fn solve_part2(input: &BingoGame) -> Result<u64, Error> {
+    let wins = input.play(Pattern::PUZZLE);
+
+    if wins.len() < input.boards.len() {
+        return Err(Error::solve(&format!("Only {} of the {} boards win with the numbers drawn", wins.len(), input.boards.len())));
+    }
+
+    wins.last().map(|w| w.score).ok_or(Error::solve("There are no boards"))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = BingoGame;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn board(s: &str, marked: &[u32]) -> Result<BingoBoard, Error> {
+        let mut board = BingoBoard::from_str(s)?;
+        marked.iter().for_each(|n| board.mark(*n));
+        Ok(board)
+    }
+
+    const BOARD: &str = "1 2 3\n4 5 6\n7 8 9";
+
+    #[test]
+    fn patterns() -> Result<(), Error> {
+        assert!(board(BOARD, &[4, 5, 6])?.wins(Pattern::Row));
+        assert!(board(BOARD, &[2, 5, 8])?.wins(Pattern::Column));
+        assert!(board(BOARD, &[1, 5, 9])?.wins(Pattern::Diagonal));
+        assert!(board(BOARD, &[3, 5, 7])?.wins(Pattern::Diagonal));
+        assert!(!board(BOARD, &[3, 5, 9])?.wins(Pattern::Diagonal));
+        assert!(board(BOARD, &[1, 3, 7, 9])?.wins(Pattern::Corners));
+        assert!(!board(BOARD, &[1, 2, 3, 4, 5, 6, 7, 8])?.wins(Pattern::Blackout));
+        assert!(board(BOARD, &[1, 2, 3, 4, 5, 6, 7, 8, 9])?.wins(Pattern::Blackout));
+        Ok(assert!(!board(BOARD, &[3, 5, 7])?.done(Pattern::PUZZLE)))
+    }
+
+    #[test]
+    fn board_sizes() -> Result<(), Error> {
+        assert!(board("7", &[7])?.wins(Pattern::Corners));
+        assert!(BingoBoard::from_str("1 2\n3").is_err());
+        assert!(BingoBoard::from_str("1 2\n3 4\n5 6").is_err());
+        Ok(assert!(BingoBoard::from_str("").is_err()))
+    }
+
+    #[test]
+    fn win_order() -> Result<(), Error> {
+        let game = input_generator("5,1,9,2,3\n\n1 2\n3 4\n\n5 6\n7 8\n\n9 1\n2 5")?;
+        let wins = game.play(&[Pattern::Row, Pattern::Diagonal]);
+
+        assert_eq!(vec![Win { board: 2, number: 9, score: 18 }, Win { board: 0, number: 2, score: 14 }], wins);
+        assert_eq!(vec![Win { board: 2, number: 2, score: 0 }], game.play(&[Pattern::Blackout]));
+        Ok(assert!(solve_part2(&game).is_err()))
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use regex::Regex;
+use crate::utils::{parse_lines, parse_token, Error, Point2};
+use std::str::FromStr;
+use crate::solution::{Answer, Solution};
+
+// Vents are drawn point by point, so they have to stay reasonably small.
+const MAX: isize = 99_999;
+
+// Parses the coordinates in `s`, with `line` being the line `s` came
+// from so errors point at the right column.
+fn parse_point(line: &str, s: &str) -> Result<Point2, Error> {
+    let coords = s
+        .trim()
+        .split(",")
+        .map(|c| parse_token::<isize>(line, c))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if coords.len() != 2 {
+        return Err(Error::token(line, s, "Invalid number of coords"));
+    }
+    if coords.iter().any(|c| !(0..=MAX).contains(c)) {
+        return Err(Error::token(line, s, &format!("Coordinates go from 0 to {}", MAX)));
+    }
+
+    Ok(Point2::new(coords[0], coords[1]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Vent {
+    start: Point2,
+    end: Point2
+}
+
+impl FromStr for Vent {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static!{
+            static ref RE: Regex = Regex::new(r"^(?P<start>.*)? -> (?P<end>.*)$").unwrap();
+        }
+
+        let cap = RE.captures(s).ok_or_else(|| Error::token(s, s, "Expected <x>,<y> -> <x>,<y>"))?;
+
+        let start = parse_point(s, cap.name("start").map_or("", |v| v.as_str()))?;
+        let end = parse_point(s, cap.name("end").map_or("", |v| v.as_str()))?;
+
+        let d = end - start;
+        if d.x != 0 && d.y != 0 && d.x.abs() != d.y.abs() {
+            return Err(Error::token(s, s, "Vents are horizontal, vertical or diagonal at 45 degrees"));
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+#[aoc_generator(day5)]
+pub fn input_generator(input: &str) -> Result<Vec<Vent>, Error> {
+    parse_lines(input, Vent::from_str)
+}
+
+fn draw_vent(map: &mut HashMap<Point2, usize>, vent: &Vent) {
+    let direction = (vent.end - vent.start).signum();
+
+    let mut p = vent.start;
+    while p != vent.end + direction {
+        map.entry(p).and_modify(|v| *v += 1).or_insert(1);
+        p += direction;
+    }
+}
+
+fn draw_map(vents: &Vec<Vent>) -> HashMap<Point2, usize> {
+    let mut map = HashMap::new();
+
+    for v in vents {
+        draw_vent(&mut map, v);
+    }
+
+    map
+}
+
+#[aoc(day5, part1)]
+pub fn solve_part1(input: &Vec<Vent>) -> Result<usize, std::num::ParseIntError> {
+    let filtered = input.into_iter()
+        .filter(|v| v.start.x == v.end.x || v.start.y == v.end.y)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let map = draw_map(&filtered);
+    let count = map.iter().filter(|(_k, v)| *v > &1).count();
+
+    Ok(count)
+}
+
+#[aoc(day5, part2)]
+pub fn solve_part2(input: &Vec<Vent>) -> Result<usize, std::num::ParseIntError> {
+    let map = draw_map(&input);
+    let count = map.iter().filter(|(_k, v)| *v > &1).count();
+
+    Ok(count)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Vent>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
@@ -0,0 +1,102 @@
+use num::{BigUint, CheckedAdd, One, Zero};
+use crate::utils::{parse_token, Error};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day6)]
+pub fn input_generator(input: &str) -> Result<Vec<u8>, Error> {
+    let line = input.trim();
+
+    line
+        .split(",")
+        .filter(|s| *s != "")
+        .map(|s| match parse_token::<u8>(line, s)? {
+            t if t > 8 => Err(Error::token(line, s, "Timers only go up to 8")),
+            t => Ok(t),
+        })
+        .collect::<Result<Vec<_>, Error>>()
+}
+
+type Buckets<T> = [T; 9];
+
+// None once the number of fish doesn't fit into a T anymore.
+fn breed<T: Clone + CheckedAdd>(buckets: &mut Buckets<T>) -> Option<()> {
+    // This is basically a rotate_left but for better readability
+    // with regards to accounting for the new born fish, we will
+    // write it down explicitly.
+    let breeding = buckets[0].clone();
+    for i in 0..8 {
+        buckets[i] = buckets[i + 1].clone();
+    }
+    buckets[6] = buckets[6].checked_add(&breeding)?;
+    buckets[8] = breeding;
+
+    Some(())
+}
+
+fn count<T: Clone + Zero + One + CheckedAdd>(school: &[u8], days: usize) -> Option<T> {
+    let mut buckets: Buckets<T> = std::array::from_fn(|_| T::zero());
+
+    for f in school {
+        buckets[*f as usize] = buckets[*f as usize].checked_add(&T::one())?;
+    }
+
+    for _ in 0..days {
+        breed(&mut buckets)?;
+    }
+
+    buckets.iter().try_fold(T::zero(), |sum, b| sum.checked_add(b))
+}
+
+// The school doubles about every week. A few hundred fish like in the
+// puzzle outgrow a usize after about 440 days, even a single one after
+// about 510, and from then on they are counted in a BigUint.
+pub fn population(school: &[u8], days: usize) -> Answer {
+    match count::<usize>(school, days) {
+        Some(n) => n.into(),
+        None => count::<BigUint>(school, days).unwrap_or_default().into(),
+    }
+}
+
+#[aoc(day6, part1)]
+pub fn solve_part1(input: &[u8]) -> Result<Answer, Error> {
+    Ok(population(input, 80))
+}
+
+#[aoc(day6, part2)]
+pub fn solve_part2(input: &[u8]) -> Result<Answer, Error> {
+    Ok(population(input, 256))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn big_populations() -> Result<(), Error> {
+        let school = input_generator(include_str!("../../../fixtures/2021/day06/sample.txt"))?;
+        let big = population(&school, 1000);
+
+        assert_eq!(Answer::Number(26984457539), population(&school, 256));
+        assert!(matches!(big, Answer::Big(_)));
+        assert_eq!(Answer::from(count::<BigUint>(&school, 1000).unwrap_or_default()), big);
+        Ok(assert_eq!(Answer::from(count::<BigUint>(&school, 300).unwrap_or_default()), population(&school, 300)))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::utils::{parse_token, Error};
+use crate::solution::{Answer, Solution};
+
+// Every alignment up to the furthest crab is tried, so they can't be too
+// far out.
+const MAX: i32 = 99_999;
+
+#[aoc_generator(day7)]
+pub fn input_generator(input: &str) -> Result<Vec<i32>, Error> {
+    let line = input.trim();
+
+    line
+        .split(",")
+        .filter(|s| *s != "")
+        .map(|s| {
+            let crab = parse_token::<i32>(line, s)?;
+            if !(0..=MAX).contains(&crab) {
+                return Err(Error::token(line, s, &format!("Crabs are between 0 and {}", MAX)));
+            }
+            Ok(crab)
+        })
+        .collect::<Result<Vec<_>, Error>>()
+}
+
+fn fuel_consumption_id(distance: i64) -> i64 {
+    distance
+}
+
+fn fuel_consumption_gauss(distance: i64) -> i64 {
+    let n = distance;
+    n * (n + 1) / 2
+}
+
+fn calculate_fuel(crabs: &Vec<i32>, alignment: i32, fuel_consumption: fn(i64) -> i64) -> i64 {
+    crabs.iter().map(|c| fuel_consumption((c - alignment).abs() as i64)).sum()
+}
+
+fn solve(crabs: &Vec<i32>, fuel_consumption: fn(i64) -> i64) -> Result<i64, Error> {
+    let max = *crabs.iter().max().ok_or(Error::new("There are no crabs?"))?;
+
+    let min_fuel = (0..=max)
+        .map(|alignment| calculate_fuel(crabs, alignment, fuel_consumption))
+        .min()
+        .ok_or(Error::new("No fuel?"))?;
+
+    Ok(min_fuel)
+}
+
+#[aoc(day7, part1)]
+pub fn solve_part1(input: &Vec<i32>) -> Result<i64, Error> {
+    solve(input, fuel_consumption_id)
+}
+
+#[aoc(day7, part2)]
+pub fn solve_part2(input: &Vec<i32>) -> Result<i64, Error> {
+    solve(input, fuel_consumption_gauss)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
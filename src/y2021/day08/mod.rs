@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
-use crate::utils::ParseError;
+use crate::utils::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
 
 type Item = Signal;
 
@@ -11,7 +12,7 @@ pub struct Signal {
 }
 
 impl FromStr for Signal {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let stuff = s.split("|")
@@ -19,23 +20,36 @@ impl FromStr for Signal {
             .map(|v| v.to_owned())
             .collect::<Vec<_>>();
 
+        if stuff.len() != 2 {
+            return Err(Error::token(s, s, "Expected <patterns> | <output>"));
+        }
+
         let input = stuff[0].split(" ").filter(|s| *s != "").map(|v| v.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
         let output = stuff[1].split(" ").filter(|s| *s != "").map(|v| v.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        if input.len() != 10 || output.len() != 4 {
+            return Err(Error::token(s, s, "Expected 10 patterns and 4 output digits"));
+        }
+        if let Some(p) = input.iter().chain(output.iter()).find(|p| !wires(p)) {
+            let p = p.iter().collect::<String>();
+            return Err(Error::token(s, s, &format!("Expected distinct wires a to g in `{}`", p)));
+        }
+
         Ok(Signal { input, output })
     }
 }
 
+fn wires(pattern: &[char]) -> bool {
+    pattern.iter().all(|c| ('a'..='g').contains(c)) && pattern.iter().enumerate().all(|(i, c)| !pattern[..i].contains(c))
+}
+
 #[aoc_generator(day8)]
-pub fn input_generator(input: &str) -> Result<Vec<Item>, ParseError> {
-    input
-        .lines()
-        .filter(|s| *s != "")
-        .map(|s| Signal::from_str(s))
-        .collect::<Result<Vec<_>, ParseError>>()
+pub fn input_generator(input: &str) -> Result<Vec<Item>, Error> {
+    parse_lines(input, Signal::from_str)
 }
 
 #[aoc(day8, part1)]
-pub fn solve_part1(signals: &Vec<Item>) -> Result<usize, ParseError> {
+pub fn solve_part1(signals: &Vec<Item>) -> Result<usize, Error> {
     let output = signals.iter().map(|s| s.output.clone()).collect::<Vec<_>>();
     let relevant_lengths: Vec<usize> = vec![2, 4, 3, 7];
 
@@ -52,11 +66,11 @@ pub fn how_often(c: char, input: &Vec<Vec<char>>) -> usize {
     input.iter().filter(|v| v.contains(&c)).count()
 }
 
-pub fn decode_digit(map: &HashMap<char, char>, code: &Vec<char>) -> Result<usize, ParseError> {
+pub fn decode_digit(map: &HashMap<char, char>, code: &Vec<char>) -> Result<usize, Error> {
     let mut codeout = code
         .iter()
-        .map(|c| map.get(&c).ok_or(ParseError::new("Cannot map code")))
-        .collect::<Result<Vec<_>, ParseError>>()?
+        .map(|c| map.get(&c).ok_or(Error::new("Cannot map code")))
+        .collect::<Result<Vec<_>, Error>>()?
         .into_iter()
         .map(|v| *v)
         .collect::<Vec<_>>();
@@ -75,11 +89,11 @@ pub fn decode_digit(map: &HashMap<char, char>, code: &Vec<char>) -> Result<usize
         "acf" => 7,
         "abcdefg" => 8,
         "abcdfg" => 9,
-        _ => Err(ParseError::new("Unknown wire configuration"))?,
+        _ => Err(Error::new("Unknown wire configuration"))?,
     })
 }
 
-pub fn decode(map: &HashMap<char, char>, code: &Vec<Vec<char>>) -> Result<usize, ParseError> {
+pub fn decode(map: &HashMap<char, char>, code: &Vec<Vec<char>>) -> Result<usize, Error> {
     let len = code.len();
 
     code.iter().enumerate()
@@ -87,14 +101,14 @@ pub fn decode(map: &HashMap<char, char>, code: &Vec<Vec<char>>) -> Result<usize,
             let d = decode_digit(map, &s)?;
             Ok((10 as usize).pow((len - 1 - i) as u32) * d)
         })
-        .try_fold(0, |acc, d: Result<usize, ParseError>| Ok(acc + d?))
+        .try_fold(0, |acc, d: Result<usize, Error>| Ok(acc + d?))
 }
 
 #[aoc(day8, part2)]
-pub fn solve_part2(signals: &Vec<Item>) -> Result<usize, ParseError> {
+pub fn solve_part2(signals: &Vec<Item>) -> Result<usize, Error> {
     let input = signals.iter().map(|s| s.input.clone()).collect::<Vec<_>>();
     let output = signals.iter().map(|s| s.output.clone()).collect::<Vec<_>>();
-    let error = ParseError::new("Could not find wiring configuration");
+    let error = Error::new("Could not find wiring configuration");
 
     let mut sum = 0;
     for (k, i) in input.iter().enumerate() {
@@ -121,24 +135,32 @@ pub fn solve_part2(signals: &Vec<Item>) -> Result<usize, ParseError> {
         // across all digits, b appears 6 times, d appears 7 times
         let four = i.iter().find(|v| v.len() == 4).ok_or(error.clone())?;
         let unmapped = four.iter().filter(|v| !map.contains_key(v)).cloned().collect::<Vec<_>>();
-        if how_often(unmapped[0], i) == 6 {
-            map.entry(unmapped[0]).or_insert('b');
-            map.entry(unmapped[1]).or_insert('d');
+        let (x, y) = match unmapped[..] {
+            [x, y] => (x, y),
+            _ => return Err(error),
+        };
+        if how_often(x, i) == 6 {
+            map.entry(x).or_insert('b');
+            map.entry(y).or_insert('d');
         } else {
-            map.entry(unmapped[1]).or_insert('b');
-            map.entry(unmapped[0]).or_insert('d');
+            map.entry(y).or_insert('b');
+            map.entry(x).or_insert('d');
         }
 
         // pick 8, identify e and g
         // across all digits, e appears 4 times, g appears 7 times
         let eight = i.iter().find(|v| v.len() == 7).ok_or(error.clone())?;
         let unmapped = eight.iter().filter(|v| !map.contains_key(v)).cloned().collect::<Vec<_>>();
-        if how_often(unmapped[0], i) == 4 {
-            map.entry(unmapped[0]).or_insert('e');
-            map.entry(unmapped[1]).or_insert('g');
+        let (x, y) = match unmapped[..] {
+            [x, y] => (x, y),
+            _ => return Err(error),
+        };
+        if how_often(x, i) == 4 {
+            map.entry(x).or_insert('e');
+            map.entry(y).or_insert('g');
         } else {
-            map.entry(unmapped[1]).or_insert('e');
-            map.entry(unmapped[0]).or_insert('g');
+            map.entry(y).or_insert('e');
+            map.entry(x).or_insert('g');
         }
 
         let out = output[k].iter().map(|v| v.clone()).collect::<Vec<_>>();
@@ -148,37 +170,20 @@ pub fn solve_part2(signals: &Vec<Item>) -> Result<usize, ParseError> {
     Ok(sum)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::utils::ParseError;
-
-    fn sample() -> &'static str {
-        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"
-    }
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Signal>;
 
-    fn input() -> Result<Vec<Item>, ParseError> {
-        Ok(input_generator(sample())?)
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
     }
 
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(26, solve_part1(&data)?))
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
     }
 
-    #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(61229, solve_part2(&data)?))
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
     }
 }
@@ -0,0 +1,85 @@
+use crate::utils::{Error, Grid, Pos};
+use crate::solution::{Answer, Solution};
+
+type Item = i32;
+
+#[aoc_generator(day9)]
+pub fn input_generator(input: &str) -> Result<Grid<u8>, Error> {
+    Grid::parse_digits(input)
+}
+
+fn is_minimum(map: &Grid<u8>, c: Pos) -> bool {
+    map.neighbours4(c).all(|n| map[c] < map[n])
+}
+
+#[aoc(day9, part1)]
+pub fn solve_part1(input: &Grid<u8>) -> Result<Item, Error> {
+    let risk_levels = input.positions()
+        .filter(|c| is_minimum(input, *c))
+        .map(|c| input[c] as Item + 1)
+        .sum();
+
+    Ok(risk_levels)
+}
+
+fn basin_size(map: &Grid<u8>, c: Pos) -> usize {
+    let mut queue = vec![c];
+    let mut visited = Grid::new(map.width(), map.height(), false);
+    let mut basin = vec![];
+
+    while let Some(q) = queue.pop() {
+        if visited[q] {
+            continue;
+        }
+        visited[q] = true;
+
+        if map[q] == 9 {
+            continue;
+        }
+
+        basin.push(map[q]);
+        queue.extend(map.neighbours4(q));
+    }
+
+    basin.len()
+}
+
+#[aoc(day9, part2)]
+pub fn solve_part2(input: &Grid<u8>) -> Result<usize, Error> {
+    let minima = input.positions()
+        .filter(|c| is_minimum(input, *c))
+        .collect::<Vec<_>>();
+
+    let mut basin_sizes = minima.iter()
+        .map(|c| basin_size(input, *c))
+        .collect::<Vec<_>>();
+    basin_sizes.sort();
+
+    if basin_sizes.len() < 3 {
+        return Err(Error::solve("Need at least three basins"));
+    }
+
+    let result = basin_sizes.iter()
+        .skip(basin_sizes.len() - 3)
+        .product();
+
+    Ok(result)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
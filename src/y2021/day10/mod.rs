@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use crate::utils::{self, Error};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day10)]
+pub fn input_generator(input: &str) -> Result<Vec<Vec<char>>, Error> {
+    utils::parse_lines(input, |s| match s.matches(|c| !"([{<)]}>".contains(c)).next() {
+        Some(c) => Err(Error::token(s, c, "Expected a bracket")),
+        None => Ok(s.chars().collect::<Vec<_>>()),
+    })
+}
+
+enum ParseResult {
+    Corrupted(char),
+    Incomplete(Vec<char>),
+    Ok,
+}
+
+fn parse_lines(input: &Vec<Vec<char>>) -> Result<Vec<ParseResult>, Error> {
+    let open = vec!['{', '[', '(', '<'];
+    let close = vec!['}', ']', ')', '>'];
+
+    let mut result = vec![];
+
+    'outer: for line in input {
+        let mut stack = vec![];
+        for character in line {
+            if open.contains(character) {
+                stack.push(*character);
+                continue;
+            }
+
+            let index = close.iter().position(|v| v == character).ok_or(Error::new("Illegal character"))?;
+            let last = stack.pop().ok_or(Error::new("Stack is empty"))?;
+            let expected = open.get(index).ok_or(Error::new("Could not find corresponding opening char"))?;
+            if *expected != last {
+                result.push(ParseResult::Corrupted(*character));
+                continue 'outer;
+            }
+        }
+
+        if stack.is_empty() {
+            result.push(ParseResult::Ok);
+        } else {
+            result.push(ParseResult::Incomplete(stack));
+        }
+    }
+
+    Ok(result)
+}
+
+#[aoc(day10, part1)]
+pub fn solve_part1(input: &Vec<Vec<char>>) -> Result<usize, Error> {
+    let score_map = vec![(')', 3), (']', 57), ('}', 1197), ('>', 25137)].into_iter().collect::<HashMap<_,_>>();
+    let score = parse_lines(input)?.iter()
+        .filter_map(|v| if let ParseResult::Corrupted(c) = v {
+            Some(c)
+        } else {
+            None
+        })
+        .try_fold(0, |sum, v| -> Result<usize, Error> {
+            Ok(sum + score_map.get(v).ok_or(Error::new("Unexpcted closing brace"))?)
+        })?;
+
+    Ok(score)
+}
+
+fn score_incomplete_line(stack: &Vec<char>) -> Result<usize, Error> {
+    let score_map = vec![('(', 1), ('[', 2), ('{', 3), ('<', 4)].into_iter().collect::<HashMap<_,_>>();
+    stack.iter()
+        .rev()
+        .try_fold(0, |sum, v| -> Result<usize, Error> {
+            Ok(sum * 5 + score_map.get(v).ok_or(Error::new(format!("Cant score {}", v).as_str()))?)
+        })
+}
+
+#[aoc(day10, part2)]
+pub fn solve_part2(input: &Vec<Vec<char>>) -> Result<usize, Error> {
+    let parser_result = parse_lines(input)?;
+    let mut scores = parser_result.iter()
+        .filter_map(|v| if let ParseResult::Incomplete(stack) = v { Some(stack) } else { None })
+        .map(|v| score_incomplete_line(v))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    scores.sort();
+    let median = scores.get(scores.len() / 2).ok_or(Error::solve("There are no incomplete lines"))?;
+
+    Ok(*median)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::budget::Budget;
+use crate::render::Picture;
+use crate::utils::{Error, Grid, Pos};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day11)]
+pub fn input_generator(input: &str) -> Result<Grid<u8>, Error> {
+    let grid = Grid::parse_digits(input)?;
+    if grid.width() == 0 {
+        return Err(Error::new("There are no octopuses"));
+    }
+
+    Ok(grid)
+}
+
+fn flash(p: Pos, map: &mut Grid<(u8, bool)>) {
+    let neighbours = map.neighbours8(p).collect::<Vec<_>>();
+
+    for n in neighbours {
+        let v = &mut map[n];
+        v.0 += 1;
+
+        if v.0 == 10 {
+            v.1 = true;
+            flash(n, map);
+        }
+    }
+}
+
+// Runs a single step and returns how many octopuses flashed in it.
+fn step(map: &mut Grid<(u8, bool)>) -> usize {
+    let positions = map.positions().collect::<Vec<_>>();
+
+    for p in &positions {
+        let v = &mut map[*p];
+        v.0 += 1;
+
+        if v.0 == 10 {
+            v.1 = true;
+            flash(*p, map);
+        }
+    }
+
+    let mut flash_count = 0;
+    for p in &positions {
+        let v = &mut map[*p];
+        if v.1 {
+            v.0 = 0;
+            flash_count += 1;
+        }
+        v.1 = false;
+    }
+
+    flash_count
+}
+
+// Octopuses that just flashed are back at 0 and drawn white, the others
+// get brighter as they charge up.
+fn picture(map: &Grid<(u8, bool)>) -> Picture {
+    Picture::grey(map, |(v, _)| if *v == 0 { 255 } else { *v * 20 })
+}
+
+// The octopuses before the first step and after each of the next `steps`.
+pub fn frames(input: &Grid<u8>, steps: usize) -> Result<Vec<Picture>, Error> {
+    let mut map = input.map(|v| (*v, false));
+    let mut frames = vec![picture(&map)];
+
+    for _ in 0..steps {
+        step(&mut map);
+        frames.push(picture(&map));
+    }
+
+    Ok(frames)
+}
+
+#[aoc(day11, part1)]
+pub fn solve_part1(input: &Grid<u8>) -> Result<usize, Error> {
+    let mut map = input.map(|v| (*v, false));
+    let mut flash_count = 0;
+
+    for _ in 0..100 {
+        flash_count += step(&mut map);
+    }
+
+    Ok(flash_count)
+}
+
+const ROUNDS: u64 = 10000;
+
+#[aoc(day11, part2)]
+pub fn solve_part2(input: &Grid<u8>) -> Result<usize, Error> {
+    solve_part2_within(input, &mut Budget::steps(ROUNDS))
+}
+
+// Every round is a step, the octopuses might never flash all at once.
+pub fn solve_part2_within(input: &Grid<u8>, budget: &mut Budget) -> Result<usize, Error> {
+    let mut map = input.map(|v| (*v, false));
+    let total = map.width() * map.height();
+    let mut round = 0;
+
+    loop {
+        budget.step(|| format!("The octopuses have not flashed all at once in {} rounds", round))?;
+        round += 1;
+
+        if step(&mut map) == total {
+            return Ok(round);
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn part2_within(input: &Self::Input, budget: &mut Budget) -> Result<Answer, Error> {
+        Ok(solve_part2_within(input, budget)?.into())
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::hash::{Hash, Hasher};
-use crate::utils::ParseError;
+use crate::utils::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Cave {
@@ -13,7 +14,7 @@ pub enum Cave {
 }
 
 impl FromStr for Cave {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let is_lowercase = s.to_lowercase().as_str() == s;
@@ -38,21 +39,34 @@ pub struct Caves {
 }
 
 impl FromStr for Caves {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
-        let paths = s.lines()
-            .map(|l| l.split("-")
+        let paths = parse_lines(s, |l| {
+            let path = l.split("-")
                 .map(|v| Cave::from_str(v))
-                .collect::<Result<Vec<_>, ParseError>>())
-            .collect::<Result<Vec<_>, ParseError>>()?;
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            if path.len() != 2 {
+                return Err(Error::token(l, l, "Expected <cave>-<cave>"));
+            }
+            // Paths could go back and forth between them forever.
+            if let (Cave::Big(_), Cave::Big(_)) = (&path[0], &path[1]) {
+                return Err(Error::token(l, l, "Big caves cannot be next to each other"));
+            }
+
+            Ok(path)
+        })?;
+        if paths.is_empty() {
+            return Err(Error::new("There are no caves"));
+        }
 
         Ok(Caves { paths })
     }
 }
 
 #[aoc_generator(day12)]
-pub fn input_generator(input: &str) -> Result<Caves, ParseError> {
+pub fn input_generator(input: &str) -> Result<Caves, Error> {
     Caves::from_str(input)
 }
 
@@ -109,75 +123,31 @@ pub fn travel(start: Cave, caves: &Caves, path: Vec<Cave>, visited: HashMap<Cave
 }
 
 #[aoc(day12, part1)]
-pub fn solve_part1(input: &Caves) -> Result<usize, ParseError> {
+pub fn solve_part1(input: &Caves) -> Result<usize, Error> {
     let valid_paths = travel(Cave::Start, input, vec![], HashMap::new(), false);
     Ok(valid_paths.len())
 }
 
 #[aoc(day12, part2)]
-pub fn solve_part2(input: &Caves) -> Result<usize, ParseError> {
+pub fn solve_part2(input: &Caves) -> Result<usize, Error> {
     let valid_paths = travel(Cave::Start, input, vec![], HashMap::new(), true);
     Ok(valid_paths.len())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::utils::ParseError;
-
-    fn sample1() -> &'static str {
-        "start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end
-dc-end"
-    }
+pub struct Day12;
 
-    fn input1() -> Result<Caves, ParseError> {
-        Ok(input_generator(sample1())?)
-    }
-
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input1()?;
-        Ok(assert_eq!(10, solve_part1(&data)?))
-    }
-
-    #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input1()?;
-        Ok(assert_eq!(36, solve_part2(&data)?))
-    }
-
-    fn sample2() -> &'static str {
-        "dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc"
-    }
+impl Solution for Day12 {
+    type Input = Caves;
 
-    fn input2() -> Result<Caves, ParseError> {
-        Ok(input_generator(sample2())?)
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
     }
 
-    #[test]
-    fn part1_sample2() -> Result<(), ParseError> {
-        let data = input2()?;
-        Ok(assert_eq!(19, solve_part1(&data)?))
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
     }
 
-    #[test]
-    fn part2_sample2() -> Result<(), ParseError> {
-        let data = input2()?;
-        Ok(assert_eq!(103, solve_part2(&data)?))
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
     }
 }
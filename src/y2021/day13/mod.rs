@@ -0,0 +1,240 @@
+use std::cmp::{max, min};
+use std::str::FromStr;
+use crate::render::Picture;
+use crate::utils::{blocks, parse_lines, parse_token, Error, Grid};
+use crate::solution::{self, Answer, Solution};
+
+// The paper is drawn in full, dots far out would make it enormous.
+const MAX_AREA: usize = 4_000_000;
+
+type C = usize;
+type Coords = (C, C);
+
+#[derive(Debug)]
+pub enum FoldInstruction {
+    X(usize),
+    Y(usize),
+}
+
+impl FromStr for FoldInstruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, <Self as std::str::FromStr>::Err> {
+        let instr = s.strip_prefix("fold along ").ok_or_else(|| Error::token(s, s, "Expected fold along <axis>=<n>"))?;
+        let (axis, which) = instr.split_once("=").ok_or_else(|| Error::token(s, instr, "Expected <axis>=<n>"))?;
+        let which = parse_token::<usize>(s, which)?;
+
+        Ok(match (axis, which) {
+            ("y", w) => FoldInstruction::Y(w),
+            ("x", w) => FoldInstruction::X(w),
+            (_, _) => Err(Error::token(s, axis, "Unknown axis"))?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Paper {
+    dots: Vec<Coords>,
+    fold: Vec<FoldInstruction>,
+}
+
+#[aoc_generator(day13)]
+pub fn input_generator(input: &str) -> Result<Paper, Error> {
+    let parts = blocks(input);
+
+    if parts.len() != 2 {
+        return Err(Error::new("Expected dots and fold instructions separated by an empty line"));
+    }
+
+    let dots = parse_lines(parts[0].1, |s| {
+        let (x, y) = s.split_once(",").ok_or_else(|| Error::token(s, s, "Expected <x>,<y>"))?;
+        Ok((parse_token::<usize>(s, x)?, parse_token::<usize>(s, y)?))
+    }).map_err(|e| e.at_line(parts[0].0))?;
+    let fold = parse_lines(parts[1].1, FoldInstruction::from_str).map_err(|e| e.at_line(parts[1].0))?;
+
+    if fold.is_empty() {
+        return Err(Error::new("Expected at least one fold instruction").at_line(parts[1].0));
+    }
+
+    let too_large = Error::new(&format!("The paper is larger than {} dots", MAX_AREA));
+    let mut last = dots.iter().fold((0, 0), |last, d| (max(last.0, d.0), max(last.1, d.1)));
+    // Folding cuts the paper to the fold, or pads it if that is further out.
+    let mut size = (last.0 + 1, last.1 + 1);
+    if size.0.saturating_mul(size.1) > MAX_AREA {
+        return Err(too_large.at_line(parts[0].0));
+    }
+
+    // The half that is folded over may not be bigger than the other one,
+    // its dots would end up off the paper.
+    for (i, f) in fold.iter().enumerate() {
+        let (edge, side, w) = match f {
+            FoldInstruction::X(w) => (&mut last.0, &mut size.0, *w),
+            FoldInstruction::Y(w) => (&mut last.1, &mut size.1, *w),
+        };
+
+        if edge.saturating_sub(w) > w {
+            return Err(Error::new("The fold is too close to the edge of the paper").at_line(parts[1].0 + i));
+        }
+        *edge = min(*edge, w.saturating_sub(1));
+        *side = w;
+
+        if size.0.saturating_mul(size.1) > MAX_AREA {
+            return Err(too_large.at_line(parts[1].0 + i));
+        }
+    }
+
+    Ok(Paper { dots, fold })
+}
+
+fn build_map(input: &Paper) -> Vec<Vec<bool>> {
+    let mut size = (0, 0);
+
+    for d in &input.dots {
+        size.0 = max(size.0, d.0);
+        size.1 = max(size.1, d.1);
+    }
+    size = (size.0 + 1, size.1 + 1);
+
+    let mut map = vec![vec![false; size.0]; size.1];
+
+    for d in &input.dots {
+        map[d.1][d.0] = true;
+    }
+
+    map
+}
+
+fn fold_x(input: &mut Vec<Vec<bool>>, w: usize) {
+    for y in 0..input.len() {
+        for x in w+1..input[y].len() {
+            input[y][w+w-x] |= input[y][x];
+        }
+    }
+
+    for y in 0..input.len() {
+        input[y].resize(w, false);
+    }
+}
+
+fn fold_y(input: &mut Vec<Vec<bool>>, w: usize) {
+    for y in w+1..input.len() {
+        for x in 0..input[y].len() {
+            input[w+w-y][x] |= input[y][x];
+        }
+    }
+
+    input.resize(w, vec![]);
+}
+
+fn fold(input: &mut Vec<Vec<bool>>, instruction: &FoldInstruction) {
+    match instruction {
+        FoldInstruction::X(w) => fold_x(input, *w),
+        FoldInstruction::Y(w) => fold_y(input, *w),
+    }
+}
+
+fn render(input: &Vec<Vec<bool>>) -> String {
+    let mut result = String::new();
+
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            if input[y][x] {
+                result.push('#');
+            } else {
+                result.push(' ');
+            }
+        }
+        result.push('\n');
+    }
+
+    result
+}
+
+#[aoc(day13, part1)]
+pub fn solve_part1(input: &Paper) -> Result<usize, Error> {
+    let mut map = build_map(input);
+
+    fold(&mut map, &input.fold[0]);
+
+    let result: usize = map.iter().map(|r| r.iter().filter(|c| **c).count()).sum();
+
+    Ok(result)
+}
+
+#[aoc(day13, part2)]
+pub fn solve_part2(input: &Paper) -> Result<usize, Error> {
+    let mut map = build_map(input);
+
+    for instruction in &input.fold {
+        fold(&mut map, instruction);
+    }
+
+    solution::artefact("letters", render(&map));
+
+    Ok(0)
+}
+
+// Dark dots on white paper.
+fn picture(map: &[Vec<bool>]) -> Result<Picture, Error> {
+    Ok(Picture::grey(&Grid::from_rows(map.to_vec())?, |dot| if *dot { 0 } else { 255 }))
+}
+
+// The paper before folding and after each of the first `steps` folds.
+pub fn frames(input: &Paper, steps: usize) -> Result<Vec<Picture>, Error> {
+    let mut map = build_map(input);
+    let mut frames = vec![picture(&map)?];
+
+    for instruction in input.fold.iter().take(steps) {
+        fold(&mut map, instruction);
+        frames.push(picture(&map)?);
+    }
+
+    Ok(frames)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Paper;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::Error;
+
+    #[test]
+    fn part2_draws_letters() -> Result<(), Error> {
+        let data = input_generator(include_str!("../../../fixtures/2021/day13/sample.txt"))?;
+        let (result, artefacts) = solution::collect_artefacts(|| solve_part2(&data));
+        result?;
+        Ok(assert_eq!("#####\n#   #\n#   #\n#   #\n#####\n     \n     \n", artefacts[0].content))
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = input_generator("6,10\n0,14\n\nfold along y=7\nfold along x=2\n").unwrap_err();
+        assert_eq!("line 5: The fold is too close to the edge of the paper", error.to_string());
+
+        let error = input_generator("6,10\n0,14\n\n\n").unwrap_err();
+        assert_eq!("line 4: Expected at least one fold instruction", error.to_string());
+
+        let error = input_generator("0,0\n99999,99999\n\nfold along y=7\n").unwrap_err();
+        assert_eq!("line 1: The paper is larger than 4000000 dots", error.to_string());
+
+        let error = input_generator("6,10\n0,14\n\nfold along y=7\nfold along x=99999999\n").unwrap_err();
+        assert_eq!("line 5: The paper is larger than 4000000 dots", error.to_string());
+    }
+}
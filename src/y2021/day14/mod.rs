@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+use std::str::FromStr;
+use num::{BigUint, CheckedAdd, One, Zero};
+use regex::Regex;
+use crate::utils::{blocks, parse_lines, Error};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Rule {
+    first: char,
+    second: char,
+    insert: char,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static!{
+            static ref RE: Regex = Regex::new(r"^(?P<first>[A-Z])(?P<second>[A-Z]) -> (?P<insert>[A-Z])$").unwrap();
+        }
+
+        let cap = RE.captures(s).ok_or_else(|| Error::token(s, s, "Expected <element><element> -> <element>"))?;
+        let element = |name| cap
+            .name(name)
+            .and_then(|v| v.as_str().chars().next())
+            .ok_or_else(|| Error::token(s, s, "Missing element"));
+
+        let first = element("first")?;
+        let second = element("second")?;
+        let insert = element("insert")?;
+
+        Ok(Self { first, second, insert })
+    }
+}
+
+#[derive(Debug)]
+pub struct Formula {
+    template: Vec<char>,
+    rules: Vec<Rule>,
+}
+
+impl Formula {
+    pub fn find_insert(&self, first: char, second: char) -> Result<char, Error> {
+        let rule = self.rules.iter().find(|v| v.first == first && v.second == second).ok_or(Error::new("Could not find rule"))?;
+
+        Ok(rule.insert)
+    }
+}
+
+#[aoc_generator(day14)]
+pub fn input_generator(input: &str) -> Result<Formula, Error> {
+    let parts = blocks(input);
+    let first = parts.first().and_then(|(_, s)| s.lines().next()).unwrap_or("");
+    if first.is_empty() {
+        return Err(Error::new("Expected a polymer template"));
+    }
+    if let Some(c) = first.matches(|c: char| !c.is_ascii_uppercase()).next() {
+        return Err(Error::token(first, c, "Expected an element").at_line(1));
+    }
+
+    let template = first.chars().collect::<Vec<_>>();
+    let rules = parts
+        .get(1)
+        .map(|(line, s)| parse_lines(s, Rule::from_str).map_err(|e| e.at_line(*line)))
+        .unwrap_or(Ok(vec![]))?;
+
+    Ok(Formula { template, rules })
+}
+
+// Builds the whole polymer, which is only feasible for a few steps.
+fn grow(input: &Formula, steps: usize) -> Result<usize, Error> {
+    let mut result = input.template.clone();
+
+    for _ in 0..steps {
+        let mut inserts = vec![];
+        for w in result.windows(2) {
+            let insert = input.find_insert(w[0], w[1])?;
+            inserts.push(insert);
+        }
+
+        result.reserve(inserts.len()+2);
+        for (i, v) in inserts.iter().enumerate() {
+            result.insert(i * 2 + 1, *v);
+        }
+    }
+
+    let mut hist = HashMap::new();
+    for c in result {
+        hist.entry(c).and_modify(|v| *v += 1).or_insert(1);
+    }
+
+    let max = hist.iter().map(|(_, v)| v).max().ok_or(Error::new("Cant find max"))?;
+    let min = hist.iter().map(|(_, v)| v).min().ok_or(Error::new("Cant find min"))?;
+
+    Ok(max-min)
+}
+
+fn add<K: Eq + Hash, T: Zero + CheckedAdd>(counts: &mut HashMap<K, T>, key: K, v: &T) -> Option<()> {
+    let count = counts.entry(key).or_insert_with(T::zero);
+    *count = count.checked_add(v)?;
+    Some(())
+}
+
+// Only counts the pairs of elements in the polymer. Gives None once the
+// counts don't fit into a T anymore.
+fn count_pairs<T>(input: &Formula, steps: usize) -> Result<Option<T>, Error>
+where
+    T: Clone + Ord + Zero + One + CheckedAdd + Sub<Output = T>,
+{
+    let mut map = HashMap::new();
+    let last = input.template.iter().last().ok_or(Error::new("Has no last char"))?;
+
+    if !input.template.windows(2).all(|w| add(&mut map, (w[0], w[1]), &T::one()).is_some()) {
+        return Ok(None);
+    }
+
+    for _ in 0..steps {
+        let mut next = HashMap::new();
+        for ((first, second), v) in &map {
+            let insert = input.find_insert(*first, *second)?;
+            if add(&mut next, (*first, insert), v).and_then(|_| add(&mut next, (insert, *second), v)).is_none() {
+                return Ok(None);
+            }
+        }
+        map = next;
+    }
+
+    // Every element is the first of a pair, except for the last one.
+    let mut hist = HashMap::new();
+    let elements = map.iter().map(|((first, _), v)| (*first, v.clone())).chain([(*last, T::one())]);
+    if !elements.into_iter().all(|(e, v)| add(&mut hist, e, &v).is_some()) {
+        return Ok(None);
+    }
+
+    let max = hist.values().max().ok_or(Error::new("Cant find max"))?;
+    let min = hist.values().min().ok_or(Error::new("Cant find min"))?;
+
+    Ok(Some(max.clone() - min.clone()))
+}
+
+// The polymer doubles in length with every step, after about 60 steps the
+// elements are counted in a BigUint.
+pub fn spread(input: &Formula, steps: usize) -> Result<Answer, Error> {
+    match count_pairs::<usize>(input, steps)? {
+        Some(n) => Ok(n.into()),
+        None => Ok(count_pairs::<BigUint>(input, steps)?.unwrap_or_default().into()),
+    }
+}
+
+#[aoc(day14, part1)]
+pub fn solve_part1(input: &Formula) -> Result<usize, Error> {
+    grow(input, 10)
+}
+
+#[aoc(day14, part2)]
+pub fn solve_part2(input: &Formula) -> Result<Answer, Error> {
+    spread(input, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Formula;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen::{self, Rng};
+
+    #[test]
+    fn counting_pairs_matches_growing() -> Result<(), Error> {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let input = input_generator(&gen::y2021::day14(&mut rng, 1 + seed as usize % 4))?;
+
+            for steps in 0..=8 {
+                assert_eq!(Some(grow(&input, steps)?), count_pairs(&input, steps)?, "seed {}, {} steps", seed, steps);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn big_polymers() -> Result<(), Error> {
+        let input = input_generator(include_str!("../../../fixtures/2021/day14/sample.txt"))?;
+        let big = spread(&input, 150)?;
+
+        assert_eq!(Answer::Number(2188189693529), spread(&input, 40)?);
+        assert_eq!(None, count_pairs::<usize>(&input, 100)?);
+        assert_eq!(Answer::from(count_pairs::<BigUint>(&input, 100)?.unwrap_or_default()), spread(&input, 100)?);
+        assert!(matches!(big, Answer::Big(_)));
+        Ok(assert_eq!(Answer::from(count_pairs::<BigUint>(&input, 150)?.unwrap_or_default()), big))
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+use std::collections::HashMap;
+use crate::utils::{Error, Grid, Pos};
+use pathfinding::prelude::dijkstra;
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day15)]
+pub fn input_generator(input: &str) -> Result<Grid<u8>, Error> {
+    Grid::parse_digits(input)
+}
+
+fn get_neighbors(p: &Pos, map: &Grid<u8>) -> Vec<(Pos, usize)> {
+    map.neighbours4(*p)
+        .map(|n| (n, map[n] as usize))
+        .collect()
+}
+
+fn next(q: &HashSet<Pos>, dist: &HashMap<Pos, usize>) -> Option<Pos> {
+    dist.iter()
+        .filter(|(k, _)| !q.contains(*k))
+        .max_by(|a, b| b.1.cmp(&a.1))
+        .map(|(k, _)| *k)
+}
+
+fn my_dijkstra(start: &Pos, end: &Pos, map: &Grid<u8>) -> Result<usize, Error> {
+    let mut visited = HashSet::new();
+    let mut dist = HashMap::new();
+
+    dist.entry(*start).or_insert(0);
+
+    while let Some(u) = next(&visited, &dist) {
+        visited.insert(u);
+
+        let neighbors = get_neighbors(&u, map);
+        for n in neighbors {
+            let new_dist = dist.get(&u).ok_or(Error::new("Should not happen"))? + n.1;
+            if let Some(&old_dist) = dist.get(&n.0) {
+                if new_dist < old_dist {
+                    dist.entry(n.0).and_modify(|v| *v = new_dist);
+                }
+            } else {
+                dist.entry(n.0).or_insert(new_dist);
+            }
+        }
+    }
+
+    dist.get(end).copied().ok_or(Error::solve("There is no path to the bottom right"))
+}
+
+fn bottom_right(map: &Grid<u8>) -> Result<Pos, Error> {
+    if map.width() == 0 || map.height() == 0 {
+        return Err(Error::solve("The map is empty"));
+    }
+
+    Ok((map.width() - 1, map.height() - 1))
+}
+
+#[aoc(day15, part1)]
+pub fn solve_part1(input: &Grid<u8>) -> Result<usize, Error> {
+    let start: Pos = (0, 0);
+    let end = bottom_right(input)?;
+    let total_risk = my_dijkstra(&start, &end, input)?;
+
+    Ok(total_risk)
+}
+
+// The full map is the original one repeated five times in both directions,
+// with the risk going up by one (wrapping from 9 back to 1) per repetition.
+fn expand(map: &Grid<u8>) -> Grid<u8> {
+    let (width, height) = (map.width(), map.height());
+
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let increase = x / width + y / height;
+        ((map[(x % width, y % height)] as usize + increase + 8) % 9 + 1) as u8
+    })
+}
+
+#[aoc(day15, part2)]
+pub fn solve_part2(input: &Grid<u8>) -> Result<usize, Error> {
+    let expansion = expand(input);
+
+    let start: Pos = (0, 0);
+    let end = bottom_right(&expansion)?;
+    let result = dijkstra(&start, |p| get_neighbors(p, &expansion), |&p| p == end).ok_or(Error::new("Pathfinding error"))?;
+
+    let total_risk = result.1;
+
+    Ok(total_risk)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zero_risk() -> Result<(), Error> {
+        let expansion = expand(&input_generator("0")?);
+
+        assert_eq!(vec![9, 1, 2, 3, 4], (0..5).map(|x| expansion[(x, 0)]).collect::<Vec<_>>());
+        Ok(assert!(solve_part2(&input_generator("10\n11")?).is_ok()))
+    }
+}
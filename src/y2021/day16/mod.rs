@@ -1,19 +1,24 @@
 use std::cmp::{min, max};
 use std::num::ParseIntError;
-use crate::utils::ParseError;
+use crate::utils::Error;
+use crate::solution::{Answer, Solution};
 
 #[aoc_generator(day16)]
-pub fn input_generator(input: &str) -> Result<Vec<u8>, ParseError> {
-    Ok(input
+pub fn input_generator(input: &str) -> Result<Vec<u8>, Error> {
+    let (i, line) = input
         .lines()
-        .filter(|s| *s != "")
-        .take(1)
-        .next().ok_or(ParseError::new("No input"))?
-        .chars()
-        .collect::<Vec<_>>()
-        .chunks(2)
-        .map(|c| u8::from_str_radix(&c.iter().collect::<String>(), 16))
-        .collect::<Result<Vec<_>, ParseIntError>>()?)
+        .enumerate()
+        .find(|(_, s)| *s != "")
+        .ok_or(Error::new("No input"))?;
+
+    (0..line.len())
+        .step_by(2)
+        .map(|n| {
+            let byte = line.get(n..min(n + 2, line.len())).ok_or_else(|| Error::token(line, line, "Invalid hex string"))?;
+            u8::from_str_radix(byte, 16).map_err(|e| Error::token(line, byte, "Invalid hex byte").with_source(e))
+        })
+        .collect::<Result<Vec<_>, Error>>()
+        .map_err(|e| e.at_line(i + 1))
 }
 
 #[derive(Debug)]
@@ -38,31 +43,31 @@ enum Packet {
 }
 
 impl Packet {
-    fn value(&self) -> Result<usize, ParseError> {
+    fn value(&self) -> Result<usize, Error> {
         if let Packet::Val(v) = self {
             return Ok(v.value);
         }
 
         if let Packet::Op(o) = self {
             let value = match o.type_id {
-                0 => o.packets.iter().map(|p| p.value()).try_fold(0, |a, v| -> Result<usize, ParseError> { Ok(a + v?) })?,
-                1 => o.packets.iter().map(|p| p.value()).try_fold(1, |a, v| -> Result<usize, ParseError> { Ok(a * v?) })?,
-                2 => o.packets.iter().map(|p| p.value()).try_fold(usize::MAX, |a, v| -> Result<usize, ParseError> { Ok(min(a, v?)) })?,
-                3 => o.packets.iter().map(|p| p.value()).try_fold(0, |a, v| -> Result<usize, ParseError> { Ok(max(a, v?)) })?,
+                0 => o.packets.iter().map(|p| p.value()).try_fold(0, |a, v| -> Result<usize, Error> { a.checked_add(v?).ok_or(Error::solve("The sum is too large")) })?,
+                1 => o.packets.iter().map(|p| p.value()).try_fold(1, |a, v| -> Result<usize, Error> { a.checked_mul(v?).ok_or(Error::solve("The product is too large")) })?,
+                2 => o.packets.iter().map(|p| p.value()).try_fold(usize::MAX, |a, v| -> Result<usize, Error> { Ok(min(a, v?)) })?,
+                3 => o.packets.iter().map(|p| p.value()).try_fold(0, |a, v| -> Result<usize, Error> { Ok(max(a, v?)) })?,
                 5 => Packet::gt(o)?,
                 6 => Packet::lt(o)?,
                 7 => Packet::eq(o)?,
-                _ => Err(ParseError::new("Unknown operator"))?
+                _ => Err(Error::new("Unknown operator"))?
             };
             return Ok(value);
         }
 
-        Err(ParseError::new("Unknown packet type"))
+        Err(Error::new("Unknown packet type"))
     }
 
-    fn gt(o: &Operator) -> Result<usize, ParseError> {
+    fn gt(o: &Operator) -> Result<usize, Error> {
         if o.packets.len() != 2 {
-            return Err(ParseError::new("Invalid number of operands"));
+            return Err(Error::new("Invalid number of operands"));
         }
 
         Ok(if o.packets[0].value()? > o.packets[1].value()? {
@@ -72,9 +77,9 @@ impl Packet {
         })
     }
 
-    fn lt(o: &Operator) -> Result<usize, ParseError> {
+    fn lt(o: &Operator) -> Result<usize, Error> {
         if o.packets.len() != 2 {
-            return Err(ParseError::new("Invalid number of operands"));
+            return Err(Error::new("Invalid number of operands"));
         }
 
         Ok(if o.packets[0].value()? < o.packets[1].value()? {
@@ -84,9 +89,9 @@ impl Packet {
         })
     }
 
-    fn eq(o: &Operator) -> Result<usize, ParseError> {
+    fn eq(o: &Operator) -> Result<usize, Error> {
         if o.packets.len() != 2 {
-            return Err(ParseError::new("Invalid number of operands"));
+            return Err(Error::new("Invalid number of operands"));
         }
 
         Ok(if o.packets[0].value()? == o.packets[1].value()? {
@@ -108,24 +113,24 @@ impl Parser {
         Parser { value, pointer: 0 }
     }
 
-    fn parse(&mut self) -> Result<Packet, ParseError> {
+    fn parse(&mut self) -> Result<Packet, Error> {
         let packet = self.parse_packet()?;
         Ok(packet)
     }
 
-    fn parse_packet(&mut self) -> Result<Packet, ParseError> {
-        let version = Parser::to_u8(&self.next(3))?;
-        let type_id = Parser::to_u8(&self.next(3))?;
+    fn parse_packet(&mut self) -> Result<Packet, Error> {
+        let version = Parser::to_u8(&self.next(3)?)?;
+        let type_id = Parser::to_u8(&self.next(3)?)?;
 
         let packet = if type_id == 4 {
             let value = self.read_value()?;
             Packet::Val(Value { version, value })
         } else {
-            let length_id = Parser::to_u8(&self.next(1))?;
+            let length_id = Parser::to_u8(&self.next(1)?)?;
             let length = if length_id == 0 {
-                Parser::to_usize(&self.next(15))?
+                Parser::to_usize(&self.next(15)?)?
             } else {
-                Parser::to_usize(&self.next(11))?
+                Parser::to_usize(&self.next(11)?)?
             };
 
             let packets = if length_id == 0 {
@@ -140,7 +145,7 @@ impl Parser {
         Ok(packet)
     }
 
-    fn parse_packets_bit_length(&mut self, length: usize) -> Result<Vec<Packet>, ParseError> {
+    fn parse_packets_bit_length(&mut self, length: usize) -> Result<Vec<Packet>, Error> {
         let mut children = vec![];
         let current_pointer = self.pointer;
         while self.pointer < current_pointer + length {
@@ -151,7 +156,7 @@ impl Parser {
         Ok(children)
     }
 
-    fn parse_packets_number(&mut self, length: usize) -> Result<Vec<Packet>, ParseError> {
+    fn parse_packets_number(&mut self, length: usize) -> Result<Vec<Packet>, Error> {
         let mut children = vec![];
         while children.len() != length {
             let packet = self.parse_packet()?;
@@ -161,10 +166,10 @@ impl Parser {
         Ok(children)
     }
 
-    fn next(&mut self, n: usize) -> Vec<char> {
-        let result = self.value.iter().skip(self.pointer).take(n).cloned().collect::<Vec<_>>();
+    fn next(&mut self, n: usize) -> Result<Vec<char>, Error> {
+        let result = self.value.get(self.pointer..self.pointer + n).ok_or(Error::new("The transmission ends in the middle of a packet"))?;
         self.pointer += n;
-        result
+        Ok(result.to_vec())
     }
 
     fn to_usize(v: &Vec<char>) -> Result<usize, ParseIntError> {
@@ -175,21 +180,18 @@ impl Parser {
         u8::from_str_radix(&v.iter().collect::<String>(), 2)
     }
 
-    fn read_value(&mut self) -> Result<usize, ParseIntError> {
-        let mut continuation = self.next(1)[0];
-        let mut total = 0;
+    fn read_value(&mut self) -> Result<usize, Error> {
+        let mut total: usize = 0;
 
-        while continuation == '1' {
-            let n = Parser::to_usize(&self.next(4))?;
-            total = total * 16 + n;
+        loop {
+            let continuation = self.next(1)?;
+            let n = Parser::to_usize(&self.next(4)?)?;
+            total = total.checked_mul(16).map(|t| t + n).ok_or(Error::new("The literal value is too large"))?;
 
-            continuation = self.next(1)[0];
+            if continuation == ['0'] {
+                return Ok(total);
+            }
         }
-
-        let n = Parser::to_usize(&self.next(4))?;
-        total = total * 16 + n;
-
-        Ok(total)
     }
 }
 
@@ -205,7 +207,7 @@ fn get_version(packet: &Packet) -> usize {
 }
 
 #[aoc(day16, part1)]
-pub fn solve_part1(input: &Vec<u8>) -> Result<usize, ParseError> {
+pub fn solve_part1(input: &Vec<u8>) -> Result<usize, Error> {
     let bits = input.iter().map(|v| format!("{:08b}", v)).collect::<String>().chars().collect::<Vec<_>>();
     let mut p = Parser::new(bits);
 
@@ -216,7 +218,7 @@ pub fn solve_part1(input: &Vec<u8>) -> Result<usize, ParseError> {
 }
 
 #[aoc(day16, part2)]
-pub fn solve_part2(input: &Vec<u8>) -> Result<usize, ParseError> {
+pub fn solve_part2(input: &Vec<u8>) -> Result<usize, Error> {
     let bits = input.iter().map(|v| format!("{:08b}", v)).collect::<String>().chars().collect::<Vec<_>>();
     let mut p = Parser::new(bits);
     let packet = p.parse()?;
@@ -224,28 +226,20 @@ pub fn solve_part2(input: &Vec<u8>) -> Result<usize, ParseError> {
     Ok(packet.value()?)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::utils::ParseError;
+pub struct Day16;
 
-    fn sample() -> &'static str {
-        "A0016C880162017C3686B18A3D4780"
-    }
+impl Solution for Day16 {
+    type Input = Vec<u8>;
 
-    fn input() -> Result<Vec<u8>, ParseError> {
-        Ok(input_generator(sample())?)
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
     }
 
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(31, solve_part1(&data)?))
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
     }
 
-    #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(54, solve_part2(&data)?))
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
     }
 }
@@ -0,0 +1,161 @@
+use std::cmp::max;
+use std::str::FromStr;
+use regex::Regex;
+use crate::utils::{parse_token, Box2, Error, Point2};
+use crate::solution::{Answer, Solution};
+
+// Every velocity that could reach the area is tried, so its size decides
+// how long the parts take.
+const MAX: isize = 500;
+
+#[derive(Debug)]
+pub struct TargetArea {
+    area: Box2,
+}
+
+impl FromStr for TargetArea {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^target area: x=(?P<x_min>.*)\.\.(?P<x_max>.*), y=(?P<y_min>.*)\.\.(?P<y_max>.*)$").unwrap();
+        }
+
+        let line = s.trim();
+        let cap = RE
+            .captures(line)
+            .ok_or_else(|| Error::token(line, line, "Expected target area: x=<n>..<n>, y=<n>..<n>").at_line(1))?;
+        let bound = |name| parse_token::<isize>(line, cap.name(name).map_or("", |v| v.as_str())).map_err(|e| e.at_line(1));
+
+        let min = Point2::new(bound("x_min")?, bound("y_min")?);
+        let max = Point2::new(bound("x_max")?, bound("y_max")?);
+
+        // The probe is launched from the origin, the area has to be ahead
+        // of it and below it.
+        if !(0 < min.x && min.x <= max.x && max.x <= MAX) {
+            return Err(Error::token(line, line, &format!("Expected 0 < x min <= x max <= {}", MAX)).at_line(1));
+        }
+        if !(-MAX <= min.y && min.y <= max.y && max.y < 0) {
+            return Err(Error::token(line, line, &format!("Expected -{} <= y min <= y max < 0", MAX)).at_line(1));
+        }
+
+        Ok(TargetArea { area: Box2::new(min, max) })
+    }
+}
+
+#[aoc_generator(day17)]
+pub fn input_generator(input: &str) -> Result<TargetArea, Error> {
+    Ok(TargetArea::from_str(input)?)
+}
+
+fn shoot(start_velocity: Point2, target: &TargetArea) -> Option<isize> {
+    let target = &target.area;
+    let mut p = Point2::default();
+    let mut v = start_velocity;
+    let mut y_max = 0;
+    let mut target_hit = false;
+
+    while p.y > target.min.y && p.x < target.max.x {
+        p += v;
+        v.x = max(0, v.x - 1);
+        v.y -= 1;
+
+        if v.x == 0 && p.x < target.min.x {
+            break;
+        }
+
+        if p.y < target.min.y && v.y < 0 {
+            break;
+        }
+
+        target_hit |= target.contains(p);
+        y_max = max(p.y, y_max);
+
+        if target_hit {
+            break;
+        }
+    }
+
+    if target_hit {
+        Some(y_max)
+    } else {
+        None
+    }
+}
+
+#[aoc(day17, part1)]
+pub fn solve_part1(input: &TargetArea) -> Result<isize, Error> {
+    let target = input;
+    let mut y_max = 0;
+
+    // Going up at y the probe comes back down through 0 at -y - 1, any
+    // faster and it skips the area. Any faster to the right and it
+    // overshoots in the first step.
+    for y in target.area.min.y..-target.area.min.y {
+        for x in 0..=target.area.max.x {
+            if y == 0 && x == 0 {
+                continue;
+            }
+
+            if let Some(new_y) = shoot(Point2::new(x, y), target) {
+                y_max = max(y_max, new_y);
+            }
+        }
+    }
+
+    Ok(y_max)
+}
+
+#[aoc(day17, part2)]
+pub fn solve_part2(input: &TargetArea) -> Result<i32, Error> {
+    let target = input;
+    let mut valid = 0;
+
+    for y in target.area.min.y..-target.area.min.y {
+        for x in 0..=target.area.max.x {
+            if y == 0 && x == 0 {
+                continue;
+            }
+
+            if let Some(_) = shoot(Point2::new(x, y), target) {
+                valid += 1;
+            }
+        }
+    }
+
+    Ok(valid)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = TargetArea;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let error = input_generator("target area: x=20..30, y=-10..x\n").unwrap_err();
+        assert_eq!("line 1, column 31: Unable to parse isize `x`: invalid digit found in string", error.to_string());
+
+        assert!(input_generator("target area: x=20..4294967296, y=-4294967296..-5").is_err());
+        assert!(input_generator("target area: x=30..20, y=-10..-5").is_err());
+        assert!(input_generator("target area: x=20..30, y=-5..-10").is_err());
+        assert!(input_generator("target area: x=20..30, y=5..10").is_err());
+    }
+}
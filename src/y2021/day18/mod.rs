@@ -1,8 +1,13 @@
 use std::cell::RefCell;
 use std::cmp::max;
 use std::rc::Rc;
-use crate::utils::ParseError;
-use serde_json::{Result as SerdeResult, Value};
+use crate::utils::{parse_lines, Error};
+use serde_json::Value;
+use crate::solution::{Answer, Solution};
+
+// Reducing takes longer the bigger the numbers are, the puzzle sticks
+// to single digits.
+const MAX: u64 = 99;
 
 #[derive(Debug, Clone)]
 pub struct Pair {
@@ -26,11 +31,11 @@ pub enum Node {
 }
 
 impl Node {
-    fn from_value(v: &Value) -> Result<Self, ParseError> {
+    fn from_value(v: &Value) -> Result<Self, Error> {
         let node = match v {
             Value::Array(a) => {
                 if a.len() != 2 {
-                    Err(ParseError::new("Invalid number of children"))?
+                    Err(Error::new("Invalid number of children"))?
                 } else {
                     let left = Node::from_value(&a[0])?;
                     let right = Node::from_value(&a[1])?;
@@ -38,9 +43,10 @@ impl Node {
                 }
             },
             Value::Number(n) => {
-                Node::Number(Rc::new(RefCell::new(n.as_u64().ok_or(ParseError::new("Invalid number"))? as usize)))
+                let n = n.as_u64().filter(|&n| n <= MAX).ok_or(Error::new(&format!("Numbers are between 0 and {}", MAX)))?;
+                Node::Number(Rc::new(RefCell::new(n as usize)))
             },
-            _ => Err(ParseError::new("Invalid value type"))?
+            _ => Err(Error::new("Invalid value type"))?
         };
 
         Ok(node)
@@ -55,15 +61,21 @@ impl Node {
 }
 
 #[aoc_generator(day18)]
-pub fn input_generator(input: &str) -> Result<Vec<Value>, ParseError> {
-    let snails = input
-        .lines()
-        .filter(|s| *s != "")
-        .map(|s| serde_json::from_str(s))
-        .collect::<SerdeResult<Vec<_>>>().map_err(|_e| ParseError::new("Cannot parse JSON"))?;
+pub fn input_generator(input: &str) -> Result<Vec<Value>, Error> {
+    let snails = parse_lines(input, |s| {
+        let value: Value = serde_json::from_str(s)?;
+        if let Node::Number(_) = Node::from_value(&value)? {
+            return Err(Error::token(s, s, "Expected a pair"));
+        }
+
+        Ok(value)
+    })?;
+    if snails.is_empty() {
+        return Err(Error::new("There are no snailfish numbers"));
+    }
         // .iter()
         // .map(|v| Node::from_value(v))
-        //.collect::<Result<Vec<_>, ParseError>>()?;
+        //.collect::<Result<Vec<_>, Error>>()?;
 
     Ok(snails)
 }
@@ -160,52 +172,16 @@ impl Node {
         }
     }
 
-    fn explode_internal(&self, lt: Option<&Node>, rt: Option<&Node>, level: usize) -> Result<(bool, bool), ParseError> {
+    // Explodes the leftmost pair of two regular numbers that is nested
+    // inside four pairs or more. Reduced numbers never go deeper than that,
+    // but numbers straight from the input might.
+    fn explode_internal(&self, lt: Option<&Node>, rt: Option<&Node>, level: usize) -> Result<(bool, bool), Error> {
         if let Node::Descent(p) = self {
+            if level >= 5 {
+                let left = p.left.borrow();
+                let right = p.right.borrow();
 
-            if level < 5 {
-                let child;
-                let all;
-                {
-                    let left = p.left.borrow();
-                    let right = p.right.borrow();
-                    let result = left.explode_internal(lt, Some(&*right), level + 1)?;
-                    child = result.0;
-                    all = result.1;
-                }
-
-                if child {
-                    *p.left.borrow_mut() = Node::Number(Rc::new(RefCell::new(0)));
-                    return Ok((false, true));
-                } else if all {
-                    return Ok((false, true));
-                }
-
-                let child;
-                let all;
-                {
-                    let left = p.left.borrow();
-                    let right = p.right.borrow();
-                    let result = right.explode_internal(Some(&*left), rt, level + 1)?;
-                    child = result.0;
-                    all = result.1;
-                }
-                if child {
-                    *p.right.borrow_mut() = Node::Number(Rc::new(RefCell::new(0)));
-                }
-
-                if all {
-                    return Ok((false, true));
-                } else {
-                    return Ok((false, false));
-                }
-            }
-
-            let left = p.left.borrow();
-            let right = p.right.borrow();
-            match (level, &*left, &*right) {
-                (5, Node::Number(l), Node::Number(r)) => {
-
+                if let (Node::Number(l), Node::Number(r)) = (&*left, &*right) {
                     if let Some(lp) = lt {
                         lp.explode_from_right(*l.borrow());
                     }
@@ -214,16 +190,46 @@ impl Node {
                         rp.explode_from_left(*r.borrow());
                     }
                     return Ok((true, true));
-                },
-                _ => Err(ParseError::new("Cannot go deeper than level 5"))?
+                }
+            }
+
+            let child;
+            let all;
+            {
+                let left = p.left.borrow();
+                let right = p.right.borrow();
+                let result = left.explode_internal(lt, Some(&*right), level + 1)?;
+                child = result.0;
+                all = result.1;
+            }
+
+            if child {
+                *p.left.borrow_mut() = Node::Number(Rc::new(RefCell::new(0)));
+                return Ok((false, true));
+            } else if all {
+                return Ok((false, true));
+            }
+
+            let child;
+            let all;
+            {
+                let left = p.left.borrow();
+                let right = p.right.borrow();
+                let result = right.explode_internal(Some(&*left), rt, level + 1)?;
+                child = result.0;
+                all = result.1;
+            }
+            if child {
+                *p.right.borrow_mut() = Node::Number(Rc::new(RefCell::new(0)));
             }
 
+            return Ok((false, all));
         }
 
         Ok((false, false))
     }
 
-    fn explode(&self) -> Result<(), ParseError> {
+    fn explode(&self) -> Result<(), Error> {
         if let Node::Descent(p) = self {
             let left = p.left.borrow();
             let right = p.right.borrow();
@@ -247,17 +253,20 @@ impl Node {
         }
     }
 
-    fn add(l: Self, r: Self) -> Result<Self, ParseError> {
-        let sum = Node::Descent(Pair::new(l, r));
-        while sum.needs_reduction() {
-            if sum.height() >= 5 {
-                sum.explode()?;
+    fn reduce(self) -> Result<Self, Error> {
+        while self.needs_reduction() {
+            if self.height() >= 5 {
+                self.explode()?;
             } else {
-                sum.split();
+                self.split();
             }
         }
 
-        Ok(sum)
+        Ok(self)
+    }
+
+    fn add(l: Self, r: Self) -> Result<Self, Error> {
+        Node::Descent(Pair::new(l, r)).reduce()
     }
 
     fn magnitude(&self) -> usize {
@@ -281,9 +290,9 @@ impl Node {
 }
 
 #[aoc(day18, part1)]
-pub fn solve_part1(input: &Vec<Value>) -> Result<usize, ParseError> {
-    let snails = input.iter().map(|i| Node::from_value(i)).collect::<Result<Vec<_>, ParseError>>()?;
-    let mut sum = snails[0].clone();
+pub fn solve_part1(input: &Vec<Value>) -> Result<usize, Error> {
+    let snails = input.iter().map(|i| Node::from_value(i)).collect::<Result<Vec<_>, Error>>()?;
+    let mut sum = snails.first().ok_or(Error::solve("There are no snailfish numbers"))?.clone().reduce()?;
 
     for i in 1..snails.len() {
         sum = Node::add(sum, snails[i].clone())?;
@@ -293,7 +302,7 @@ pub fn solve_part1(input: &Vec<Value>) -> Result<usize, ParseError> {
 }
 
 #[aoc(day18, part2)]
-pub fn solve_part2(input: &Vec<Value>) -> Result<usize, ParseError> {
+pub fn solve_part2(input: &Vec<Value>) -> Result<usize, Error> {
     let mut result = 0;
 
     for i in 0..input.len() {
@@ -302,7 +311,7 @@ pub fn solve_part2(input: &Vec<Value>) -> Result<usize, ParseError> {
                 continue;
             }
 
-            let snails = input.iter().map(|i| Node::from_value(i)).collect::<Result<Vec<_>, ParseError>>()?;
+            let snails = input.iter().map(|i| Node::from_value(i)).collect::<Result<Vec<_>, Error>>()?;
             let sum = Node::add(snails[i].clone(), snails[j].clone())?.magnitude();
             result = max(sum, result);
         }
@@ -311,46 +320,64 @@ pub fn solve_part2(input: &Vec<Value>) -> Result<usize, ParseError> {
     Ok(result)
 }
 
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Value>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::utils::ParseError;
+    use crate::utils::Error;
 
-    fn parse_literal(s: &str) -> Result<Node, ParseError> {
+    fn parse_literal(s: &str) -> Result<Node, Error> {
         let value = input_generator(s)?;
         Ok(Node::from_value(&value[0])?.clone())
     }
 
     #[test]
-    fn height_5_works() -> Result<(), ParseError> {
+    fn height_5_works() -> Result<(), Error> {
         let snail = parse_literal("[[[[[9,8],1],2],3],4]")?;
 
         Ok(assert_eq!(5, snail.height()))
     }
 
     #[test]
-    fn height_1_works() -> Result<(), ParseError> {
+    fn height_1_works() -> Result<(), Error> {
         let snail = parse_literal("[1,4]")?;
 
         Ok(assert_eq!(1, snail.height()))
     }
 
     #[test]
-    fn max_1() -> Result<(), ParseError> {
+    fn max_1() -> Result<(), Error> {
         let snail = parse_literal("[[[[[9,8],1],2],3],4]")?;
 
         Ok(assert_eq!(Some(9), snail.max()))
     }
 
     #[test]
-    fn max_2() -> Result<(), ParseError> {
+    fn max_2() -> Result<(), Error> {
         let snail = parse_literal("[[[[0,7],4],[15,[0,13]]],[1,1]]")?;
 
         Ok(assert_eq!(Some(15), snail.max()))
     }
 
     #[test]
-    fn split_1() -> Result<(), ParseError> {
+    fn split_1() -> Result<(), Error> {
         let snail = parse_literal("[[[[0,7],4],[15,[0,13]]],[1,1]]")?;
 
         snail.split();
@@ -360,7 +387,7 @@ mod test {
     }
 
     #[test]
-    fn split_2() -> Result<(), ParseError> {
+    fn split_2() -> Result<(), Error> {
         let snail = parse_literal("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]")?;
 
         snail.split();
@@ -370,7 +397,7 @@ mod test {
     }
 
     #[test]
-    fn explode_1() -> Result<(), ParseError> {
+    fn explode_1() -> Result<(), Error> {
         let snail = parse_literal("[[[[[9,8],1],2],3],4]")?;
 
         snail.explode()?;
@@ -380,7 +407,7 @@ mod test {
     }
 
     #[test]
-    fn explode_2() -> Result<(), ParseError> {
+    fn explode_2() -> Result<(), Error> {
         let snail = parse_literal("[7,[6,[5,[4,[3,2]]]]]")?;
 
         snail.explode()?;
@@ -390,7 +417,7 @@ mod test {
     }
 
     #[test]
-    fn explode_3() -> Result<(), ParseError> {
+    fn explode_3() -> Result<(), Error> {
         let snail = parse_literal("[[6,[5,[4,[3,2]]]],1]")?;
 
         snail.explode()?;
@@ -399,9 +426,8 @@ mod test {
         Ok(assert_eq!(expected, &snail.print()))
     }
 
-
     #[test]
-    fn explode_4() -> Result<(), ParseError> {
+    fn explode_4() -> Result<(), Error> {
         let snail = parse_literal("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]")?;
 
         snail.explode()?;
@@ -410,9 +436,8 @@ mod test {
         Ok(assert_eq!(expected, &snail.print()))
     }
 
-
     #[test]
-    fn explode_5() -> Result<(), ParseError> {
+    fn explode_5() -> Result<(), Error> {
         let snail = parse_literal("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]")?;
 
         snail.explode()?;
@@ -422,61 +447,51 @@ mod test {
     }
 
     #[test]
-    fn magnitude_1() -> Result<(), ParseError> {
+    fn explode_deep() -> Result<(), Error> {
+        let snail = parse_literal("[[[[[[[1,2],3],4],5],6],7],8]")?;
+
+        snail.explode()?;
+
+        assert_eq!("[[[[[[0,5],4],5],6],7],8]", &snail.print());
+        assert_eq!("[[[[0,9],5],6],7]", parse_literal("[[[[[[1,2],3],4],5],6],7]")?.reduce()?.print());
+        assert_eq!(626, solve_part1(&input_generator("[[[[[[1,2],3],4],5],6],7]")?)?);
+        Ok(assert!(input_generator("7").is_err()))
+    }
+
+    #[test]
+    fn magnitude_1() -> Result<(), Error> {
         Ok(assert_eq!(143, parse_literal("[[1,2],[[3,4],5]]")?.magnitude()))
     }
 
     #[test]
-    fn magnitude_2() -> Result<(), ParseError> {
+    fn magnitude_2() -> Result<(), Error> {
         Ok(assert_eq!(1384, parse_literal("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")?.magnitude()))
     }
 
     #[test]
-    fn magnitude_3() -> Result<(), ParseError> {
+    fn magnitude_3() -> Result<(), Error> {
         Ok(assert_eq!(445, parse_literal("[[[[1,1],[2,2]],[3,3]],[4,4]]")?.magnitude()))
     }
 
     #[test]
-    fn magnitude_4() -> Result<(), ParseError> {
+    fn magnitude_4() -> Result<(), Error> {
         Ok(assert_eq!(791, parse_literal("[[[[3,0],[5,3]],[4,4]],[5,5]]")?.magnitude()))
     }
 
     #[test]
-    fn magnitude_5() -> Result<(), ParseError> {
+    fn magnitude_5() -> Result<(), Error> {
         Ok(assert_eq!(1137, parse_literal("[[[[5,0],[7,4]],[5,5]],[6,6]]")?.magnitude()))
     }
 
     #[test]
-    fn magnitude_6() -> Result<(), ParseError> {
+    fn magnitude_6() -> Result<(), Error> {
         Ok(assert_eq!(3488, parse_literal("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")?.magnitude()))
     }
 
-    fn sample() -> &'static str {
-        "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
-    }
-
-    fn input() -> Result<Vec<Value>, ParseError> {
-        Ok(input_generator(sample())?)
-    }
-
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(4140, solve_part1(&data)?))
-    }
-
     #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input()?;
-        Ok(assert_eq!(3993, solve_part2(&data)?))
+    fn rejects_large_numbers() {
+        assert!(input_generator("").is_err());
+        assert!(input_generator("[1,4294967296]").is_err());
+        assert!(parse_literal("[99,0]").is_ok());
     }
 }
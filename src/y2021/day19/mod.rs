@@ -0,0 +1,318 @@
+use std::collections::VecDeque;
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::budget::Budget;
+use crate::utils::{blocks, parse_token, Box3, Error, Point3};
+use itertools::Itertools;
+use crate::solution::{Answer, Solution};
+
+type Coords = Point3;
+
+#[derive(Debug)]
+pub struct Scanner {
+    name: String,
+    beacons: Vec<Coords>,
+}
+
+impl FromStr for Scanner {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let header = s.lines()
+            .next()
+            .ok_or(Error::new("No name found"))?;
+        let name = header
+            .strip_prefix("--- ")
+            .and_then(|h| h.strip_suffix(" ---"))
+            .filter(|h| h.starts_with("scanner "))
+            .ok_or_else(|| Error::token(header, header, "Expected --- scanner <n> ---").at_line(1))?
+            .to_owned();
+        let beacons = s.lines()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, s)| *s != "")
+            .map(|(i, l)| Scanner::parse_line(l).map_err(|e| e.at_line(i + 1)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        if beacons.is_empty() {
+            return Err(Error::new(&format!("No beacons seen by {}", name)).at_line(1));
+        }
+
+        Ok(Self { name, beacons })
+    }
+}
+
+impl Scanner {
+    fn parse_line(s: &str) -> Result<Coords, <Self as FromStr>::Err> {
+        let coords = s.split(",").map(|v| parse_token::<isize>(s, v)).collect::<Result<Vec<_>, Error>>()?;
+        if coords.len() != 3 {
+            return Err(Error::token(s, s, "Invalid number of coordinates for beacon"));
+        }
+
+        Ok(Point3::new(coords[0], coords[1], coords[2]))
+    }
+}
+
+#[aoc_generator(day19)]
+pub fn input_generator(input: &str) -> Result<Vec<Scanner>, Error> {
+    blocks(input)
+        .into_iter()
+        .filter(|(_, s)| *s != "")
+        .map(|(line, s)| Scanner::from_str(s).map_err(|e| e.at_line(line)))
+        .collect::<Result<Vec<_>, Error>>()
+}
+
+fn permutations() -> Vec<[isize; 6]> {
+    let mut result = vec![];
+
+    let sign_perms = vec![
+        vec![ 1,  1,  1],
+        vec![-1,  1,  1],
+        vec![ 1, -1,  1],
+        vec![ 1,  1, -1],
+        vec![-1, -1,  1],
+        vec![ 1, -1, -1],
+        vec![-1,  1, -1],
+        vec![-1, -1, -1],
+    ];
+
+    for p in (0..=2).permutations(3) {
+        for s in &sign_perms {
+            result.push([p[0], p[1], p[2], s[0], s[1], s[2]]);
+        }
+    }
+
+    result
+}
+
+fn transform(beacons: &Vec<Coords>, t: &[isize; 6]) -> Vec<Coords> {
+    beacons.iter()
+        .map(|b| {
+            Point3::new(t[3] * b[t[0] as usize], t[4] * b[t[1] as usize], t[5] * b[t[2] as usize])
+        })
+        .collect::<Vec<_>>()
+}
+
+fn translate(beacons: &Vec<Coords>, t: Coords) -> Vec<Coords> {
+    beacons.iter()
+        .map(|b| *b + t)
+        .collect::<Vec<_>>()
+}
+
+fn dist(a: Coords, b: Coords) -> Coords {
+    (b - a).abs()
+}
+
+#[derive(Debug)]
+pub struct BeaconMap {
+    map: HashMap<(usize, usize), Coords>,
+    beacons: Vec<Coords>,
+}
+
+impl BeaconMap {
+    fn new(b: Vec<Coords>) -> Self {
+        let map = HashMap::new();
+        let beacons = vec![];
+        let mut bmap = BeaconMap { map, beacons };
+        bmap.add(b.clone());
+        bmap
+    }
+
+    fn add(&mut self, mut new_beacons: Vec<Coords>) {
+        let len = self.beacons.len();
+        self.beacons.append(&mut new_beacons);
+
+        for i in 0..self.beacons.len() {
+            for j in len..self.beacons.len() {
+                if i >= j {
+                    continue;
+                }
+                self.map.entry((i, j)).or_insert(dist(self.beacons[i], self.beacons[j]));
+            }
+        }
+    }
+
+    fn has_unique_distance(&self, distance: Coords) -> bool {
+        self.map.iter().filter(|(_, v)| **v == distance).count() == 1
+    }
+}
+
+fn trace(map: &BeaconMap, beacons: &Vec<Coords>) -> Option<(Vec<usize>, Vec<usize>)> {
+
+    let mut pools = vec![];
+    for t in permutations() {
+        let points = transform(beacons, &t);
+        let tmp_map = BeaconMap::new(points.clone());
+        for (i, _) in points.iter().enumerate() {
+            let mut pool = tmp_map.map.iter()
+                .filter(|(k, v)| k.0 == i && map.has_unique_distance(**v))
+                .map(|(k, _)| k.1)
+                .collect::<Vec<_>>();
+            pool.push(i);
+
+            pools.push(pool);
+        }
+
+        pools.sort_by(|a, b| b.len().cmp(&a.len()));
+
+        let pool = pools.first()?.clone();
+
+        if pool.len() < 12 {
+            continue;
+        }
+
+        let matched = points.iter().enumerate().filter(|(i, _)| pool.contains(i)).map(|(_, v)| v).cloned().collect::<Vec<_>>();
+        let tm = BeaconMap::new(matched);
+
+        let mut matchpools = vec![];
+        for (j, _) in map.beacons.iter().enumerate() {
+            let mut matchpool = map.map.iter()
+                .filter(|(k, v)| k.0 == j && tm.has_unique_distance(**v))
+                .map(|(k, _)| k.1)
+                .collect::<Vec<_>>();
+            matchpool.push(j);
+            matchpools.push(matchpool);
+        }
+        matchpools.sort_by(|a, b| b.len().cmp(&a.len()));
+        let matches = matchpools.first()?.clone();
+
+        if matches.len() == pool.len() {
+            return Some((pool, matches))
+        }
+    }
+
+    None
+}
+
+fn find_transformation(map: &BeaconMap, beacons: &Vec<Coords>, existing: &Vec<usize>) -> Option<([isize; 6], Coords)> {
+    let trafos = permutations();
+
+    let original = map.beacons.iter().enumerate().filter(|(i, _)| existing.contains(i)).map(|(_, v)| v).cloned().collect::<Vec<_>>();
+    let fixpoint = *original.first()?;
+    let box2 = Box3::bounding(original.iter().cloned())?;
+
+    for t in trafos {
+        let points = transform(beacons, &t);
+        let box1 = Box3::bounding(points.iter().cloned())?;
+
+        if box1.max - box1.min == box2.max - box2.min {
+            for p in &points {
+                let translation = fixpoint - *p;
+                let translated = translate(&points, translation);
+
+                let matches = translated.iter()
+                    .filter(|p| original.contains(p))
+                    .count() == original.len();
+
+                if matches {
+                    return Some((t, translation));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+const ATTEMPTS: u64 = 1000;
+
+// Puts all scanners on the map of the first one, and returns the map and
+// where the scanners are. Every attempt to place a scanner is a step.
+fn place(input: &[Scanner], budget: &mut Budget) -> Result<(BeaconMap, Vec<Coords>), Error> {
+    let mut queue = input.iter().skip(1).collect::<VecDeque<_>>();
+    let mut map = BeaconMap::new(input.first().ok_or(Error::solve("There are no scanners"))?.beacons.clone());
+
+    // Scanner 0 is where the map starts.
+    let mut translations = vec![Coords::default()];
+    // Once all waiting scanners failed on the same map, they always will.
+    let mut misses = 0;
+
+    while let Some(q) = queue.pop_front() {
+        budget.step(|| format!("Placed {} of {} scanners", translations.len(), input.len()))?;
+
+        if let Some(t) = trace(&map, &q.beacons) {
+            trace!("{} shares {} beacons with the map", q.name, t.0.len());
+            if t.0.len() >= 12 {
+                let matched = q.beacons.iter().enumerate().filter(|(i, _)| t.0.contains(i)).map(|(_, v)| v).cloned().collect::<Vec<_>>();
+                if let Some(trans) = find_transformation(&map, &matched, &t.1) {
+                    let (rotation, translation) = trans;
+                    debug!("Placed {} at {} with rotation {:?}", q.name, translation, rotation);
+
+                    let unmatched = q.beacons.iter().enumerate().filter(|(i, _)| !t.0.contains(i)).map(|(_, v)| v).cloned().collect::<Vec<_>>();
+                    let rotated = transform(&unmatched, &rotation);
+                    let transformed = translate(&rotated, translation);
+
+                    map.add(transformed);
+                    translations.push(translation);
+                    misses = 0;
+
+                    continue;
+                }
+            }
+        }
+
+        misses += 1;
+        if misses > queue.len() {
+            error!("Nothing on the map matches {}", queue.iter().chain([&q]).map(|s| &s.name).join(", "));
+            return Err(Error::solve(&format!("Could not place {} of the scanners", queue.len() + 1)));
+        }
+
+        queue.push_back(q);
+    }
+
+    Ok((map, translations))
+}
+
+#[aoc(day19, part1)]
+pub fn solve_part1(input: &Vec<Scanner>) -> Result<usize, Error> {
+    solve_part1_within(input, &mut Budget::steps(ATTEMPTS))
+}
+
+pub fn solve_part1_within(input: &[Scanner], budget: &mut Budget) -> Result<usize, Error> {
+    let (map, _) = place(input, budget)?;
+
+    Ok(map.beacons.len())
+}
+
+#[aoc(day19, part2)]
+pub fn solve_part2(input: &Vec<Scanner>) -> Result<u64, Error> {
+    solve_part2_within(input, &mut Budget::steps(ATTEMPTS))
+}
+
+pub fn solve_part2_within(input: &[Scanner], budget: &mut Budget) -> Result<u64, Error> {
+    let (_, translations) = place(input, budget)?;
+
+    let mut distances = vec![];
+    for i in 0..translations.len() {
+        for j in 0..translations.len() {
+            distances.push(translations[i].manhattan(translations[j]) as u64);
+        }
+    }
+
+    distances.into_iter().max().ok_or(Error::solve("There are no scanners"))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn part1_within(input: &Self::Input, budget: &mut Budget) -> Result<Answer, Error> {
+        Ok(solve_part1_within(input, budget)?.into())
+    }
+
+    fn part2_within(input: &Self::Input, budget: &mut Budget) -> Result<Answer, Error> {
+        Ok(solve_part2_within(input, budget)?.into())
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::num::ParseIntError;
+use crate::render::{Picture, Render};
+use crate::utils::{Error, Grid};
+use crate::solution::{Answer, Solution};
+
+// The image is infinite, every pixel outside of `pixels` has the value
+// of `outside`.
+#[derive(Debug, Clone)]
+pub struct Image {
+    pixels: Grid<char>,
+    outside: char,
+}
+
+impl Image {
+    fn new(pixels: Grid<char>) -> Self {
+        let outside = '.';
+        Image { pixels, outside }
+    }
+}
+
+fn parse_pixels(line: &str) -> Result<Vec<char>, Error> {
+    line.char_indices()
+        .map(|(i, c)| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(Error::token(line, &line[i..i + c.len_utf8()], "Invalid pixel")),
+        })
+        .collect()
+}
+
+#[aoc_generator(day20)]
+pub fn input_generator(input: &str) -> Result<(Vec<char>, Image), Error> {
+    let mut lines = input.splitn(2, '\n');
+    let line = lines
+        .next()
+        .filter(|l| !l.is_empty())
+        .ok_or(Error::new("No image filter found"))?;
+    let filter = parse_pixels(line).map_err(|e| e.at_line(1))?;
+    if filter.len() != 512 {
+        return Err(Error::token(line, line, "Image filter needs 512 pixels").at_line(1));
+    }
+
+    let image = lines.next().unwrap_or("");
+    let pixels = Grid::parse(image, |c| match c {
+        '#' | '.' => Some(c),
+        _ => None,
+    }).map_err(|e| e.at_line(2))?;
+
+    Ok((filter, Image::new(pixels)))
+}
+
+impl Image {
+    fn get_neighbors(&self, x: isize, y: isize) -> Vec<char> {
+        let mut result = vec![];
+        let delta = vec![-1, 0, 1];
+
+        for dy in &delta {
+            for dx in &delta {
+                result.push(*self.pixels.get(x + dx, y + dy).unwrap_or(&self.outside));
+            }
+        }
+
+        result
+    }
+
+    // Every fold grows the image by one pixel on each side, pixel (x, y) of
+    // the new image is pixel (x - 1, y - 1) of the old one.
+    fn fold(&self, filter: &[char]) -> Result<Self, Error> {
+        let new_outside = match self.outside {
+            '#' => filter[511],
+            _ => filter[0],
+        };
+
+        let mut new_pixels = Grid::new(self.pixels.width() + 2, self.pixels.height() + 2, '.');
+
+        for (x, y) in new_pixels.positions().collect::<Vec<_>>() {
+            let neighbors = self.get_neighbors(x as isize - 1, y as isize - 1);
+            let index = binary_to_decimal(&neighbors)?;
+            new_pixels[(x, y)] = filter[index];
+        }
+
+        Ok(Image { outside: new_outside, pixels: new_pixels })
+    }
+
+    fn count(&self) -> usize {
+        self.pixels.iter().filter(|(_, v)| **v == '#').count()
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pixels)
+    }
+}
+
+// Only the part of the image that has been looked at, the infinite rest
+// has the same value as its border.
+impl Render for Image {
+    fn render(&self) -> Picture {
+        Picture::grey(&self.pixels, |c| if *c == '#' { 255 } else { 0 })
+    }
+}
+
+fn binary_to_decimal(s: &[char]) -> Result<usize, ParseIntError> {
+    let binary: String = s.iter().map(|c| if *c == '#' { '1' } else { '0' }).collect();
+    usize::from_str_radix(&binary, 2)
+}
+
+#[aoc(day20, part1)]
+pub fn solve_part1(input: &(Vec<char>, Image)) -> Result<usize, Error> {
+    let filter = &input.0;
+    let mut image = input.1.clone();
+
+    for _ in 0..2 {
+        image = image.fold(filter)?;
+    }
+
+    Ok(image.count())
+}
+
+#[aoc(day20, part2)]
+pub fn solve_part2(input: &(Vec<char>, Image)) -> Result<usize, Error> {
+    let filter = &input.0;
+    let mut image = input.1.clone();
+
+    for _ in 0..50 {
+        image = image.fold(filter)?;
+    }
+
+    Ok(image.count())
+}
+
+// The image before enhancing it and after each of the next `steps` times.
+pub fn frames(input: &(Vec<char>, Image), steps: usize) -> Result<Vec<Picture>, Error> {
+    let mut image = input.1.clone();
+    let mut frames = vec![image.render()];
+
+    for _ in 0..steps {
+        image = image.fold(&input.0)?;
+        frames.push(image.render());
+    }
+
+    Ok(frames)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<char>, Image);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp::max;
 use std::collections::HashMap;
-use crate::utils::ParseError;
+use crate::utils::{parse_lines, parse_token, Error};
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct Game {
@@ -51,14 +52,29 @@ impl Die {
     }
 }
 
+fn starting_position(line: &str) -> Result<usize, Error> {
+    let position = line
+        .strip_prefix("Player ")
+        .and_then(|l| l.split_once(" starting position: "))
+        .map(|(_, p)| p)
+        .ok_or_else(|| Error::token(line, line, "Expected Player <n> starting position: <n>"))?;
+
+    match parse_token(line, position)? {
+        p @ 1..=10 => Ok(p),
+        _ => Err(Error::token(line, position, "The board only has the positions 1 to 10")),
+    }
+}
+
 #[aoc_generator(day21)]
-pub fn input_generator(_input: &str) -> Game {
-    // Game::new(4, 8)
-    Game::new(1, 10)
+pub fn input_generator(input: &str) -> Result<Game, Error> {
+    match parse_lines(input, starting_position)?[..] {
+        [player1, player2] => Ok(Game::new(player1, player2)),
+        _ => Err(Error::new("Expected the starting positions of two players")),
+    }
 }
 
 #[aoc(day21, part1)]
-pub fn solve_part1(input: &Game) -> Result<usize, ParseError> {
+pub fn solve_part1(input: &Game) -> Result<usize, Error> {
     let mut game = input.clone();
     let mut die = Die::new();
 
@@ -83,7 +99,7 @@ fn number_of_wins(player: usize, games: &HashMap<Game, usize>) -> usize {
 }
 
 #[aoc(day21, part2)]
-pub fn solve_part2(input: &Game) -> Result<usize, ParseError> {
+pub fn solve_part2(input: &Game) -> Result<usize, Error> {
     let mut ongoing_games = HashMap::<Game, usize>::new();
     let initial_game = input.clone();
     ongoing_games.entry(initial_game).or_insert(1);
@@ -119,24 +135,32 @@ pub fn solve_part2(input: &Game) -> Result<usize, ParseError> {
     Ok(max(wins1, wins2))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::utils::ParseError;
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Game;
 
-    fn input() -> Game {
-        Game::new(4, 8)
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
     }
 
-    #[test]
-    fn part1_sample() -> Result<(), ParseError> {
-        let data = input();
-        Ok(assert_eq!(739785, solve_part1(&data)?))
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
-    fn part2_sample() -> Result<(), ParseError> {
-        let data = input();
-        Ok(assert_eq!(444356092776315, solve_part2(&data)?))
+    fn parse_errors() {
+        let error = input_generator("Player 1 starting position: 4\nPlayer 2 starting position: 11\n").unwrap_err();
+        assert_eq!("line 2, column 29: The board only has the positions 1 to 10 `11`", error.to_string());
+        assert!(input_generator("Player 1 starting position: 4\n").is_err());
     }
 }
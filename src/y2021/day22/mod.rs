@@ -0,0 +1,250 @@
+use crate::utils::{parse_lines, parse_token, Box3, Error, Point3};
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    On,
+    Off,
+}
+
+// Real reboot steps stay within about 100000 of the origin, further out
+// splitting cuboids could overflow.
+const MAX: isize = 999_999;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cuboid {
+    state: State,
+    bounds: Box3,
+}
+
+impl FromStr for Cuboid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(?P<state>.*) x=(?P<x_r_s>.*)?\.\.(?P<x_r_e>.*),y=(?P<y_r_s>.*)\.\.(?P<y_r_e>.*),z=(?P<z_r_s>.*)\.\.(?P<z_r_e>.*)$").unwrap();
+        }
+
+        let cap = RE
+            .captures(s)
+            .ok_or_else(|| Error::token(s, s, "Expected <on|off> x=<n>..<n>,y=<n>..<n>,z=<n>..<n>"))?;
+        let group = |name| cap.name(name).map_or("", |v| v.as_str());
+        let bound = |name| {
+            let v = parse_token::<isize>(s, group(name))?;
+            if !(-MAX..=MAX).contains(&v) {
+                return Err(Error::token(s, group(name), &format!("Coordinates are between -{} and {}", MAX, MAX)));
+            }
+            Ok(v)
+        };
+
+        let state = group("state");
+        let min = Point3::new(bound("x_r_s")?, bound("y_r_s")?, bound("z_r_s")?);
+        let max = Point3::new(bound("x_r_e")?, bound("y_r_e")?, bound("z_r_e")?);
+
+        let state = match state {
+            "on" => State::On,
+            "off" => State::Off,
+            _ => Err(Error::token(s, state, "Unknown state"))?,
+        };
+
+        Ok(Self {
+            state,
+            bounds: Box3::new(min, max),
+        })
+    }
+}
+
+#[aoc_generator(day22)]
+pub fn input_generator(input: &str) -> Result<Vec<Cuboid>, Error> {
+    parse_lines(input, Cuboid::from_str)
+}
+
+#[aoc(day22, part1)]
+pub fn solve_part1(input: &Vec<Cuboid>) -> Result<usize, Error> {
+    let mut map = HashMap::new();
+    let region = Box3::new(Point3::new(-50, -50, -50), Point3::new(50, 50, 50));
+
+    for c in input {
+        let b = match c.bounds.intersection(&region) {
+            Some(b) => b,
+            None => continue,
+        };
+
+        for x in b.min.x..=b.max.x {
+            for y in b.min.y..=b.max.y {
+                for z in b.min.z..=b.max.z {
+                    map.entry((x, y, z))
+                        .and_modify(|v| *v = c.state)
+                        .or_insert(c.state);
+                }
+            }
+        }
+    }
+
+    let ons = map.iter().filter(|(_, v)| **v == State::On).count();
+
+    Ok(ons)
+}
+
+impl Cuboid {
+    fn has_intersection(&self, c2: &Cuboid) -> bool {
+        self.bounds.intersects(&c2.bounds)
+    }
+
+    fn intersect(&self, c2: &Cuboid) -> Option<Cuboid> {
+        let state = self.state;
+        let bounds = self.bounds.intersection(&c2.bounds)?;
+
+        Some(Cuboid { state, bounds })
+    }
+
+    fn split(&self, splitter: &Cuboid) -> Vec<Cuboid> {
+        if !self.has_intersection(splitter) {
+            return vec![];
+        }
+
+        if self == splitter {
+            return vec![];
+        }
+
+        //     +-----+--+
+        //    /     /| /|
+        //   +-----+--+-+
+        //   |     |/ |/|
+        //   |     +--+ |
+        //   |        |/
+        //   +--------+
+        //
+
+        let (a, s) = (self.bounds, splitter.bounds);
+        let new_bounds = vec![
+            // get the front cuboid
+            Box3::new(a.min, Point3::new(a.max.x, a.max.y, s.min.z - 1)),
+            Box3::new(Point3::new(a.min.x, a.min.y, s.max.z + 1), a.max),
+            // get the left cuboid
+            Box3::new(Point3::new(a.min.x, a.min.y, s.min.z), Point3::new(s.min.x - 1, a.max.y, s.max.z)),
+            Box3::new(Point3::new(s.max.x + 1, a.min.y, s.min.z), Point3::new(a.max.x, a.max.y, s.max.z)),
+            // get the bottom cuboid
+            Box3::new(Point3::new(s.min.x, a.min.y, s.min.z), Point3::new(s.max.x, s.min.y - 1, s.max.z)),
+            Box3::new(Point3::new(s.min.x, s.max.y + 1, s.min.z), Point3::new(s.max.x, a.max.y, s.max.z)),
+        ];
+
+        let mut result = vec![];
+        let state = self.state;
+        for bounds in new_bounds {
+            if !bounds.is_empty() {
+                let c = Cuboid { state, bounds };
+                result.push(c);
+            }
+        }
+
+        trace!("Split {} around {} into {} cuboids", self.bounds, splitter.bounds, result.len());
+        result
+    }
+
+    fn size(&self) -> Option<usize> {
+        usize::try_from(self.bounds.volume()?).ok()
+    }
+}
+
+pub struct CuboidMerge {
+    area: Vec<Cuboid>,
+}
+
+impl CuboidMerge {
+    fn new() -> Self {
+        let area = vec![];
+        CuboidMerge { area }
+    }
+
+    fn merge(&mut self, c: &Cuboid) {
+        let mut intersects = vec![];
+        let mut replacements = vec![];
+        for i in 0..self.area.len() {
+            if let Some(icube) = self.area[i].intersect(c) {
+                intersects.push(i);
+                replacements.push(self.area[i].split(&icube));
+            }
+        }
+
+        for i in intersects.into_iter().rev() {
+            self.area.swap_remove(i);
+        }
+
+        for mut r in replacements.into_iter() {
+            self.area.append(&mut r);
+        }
+
+        if c.state == State::On {
+            self.area.push(c.clone());
+        }
+
+        debug!("{} cuboids after turning {:?} {}", self.area.len(), c.state, c.bounds);
+    }
+
+    fn count(&self) -> Result<usize, Error> {
+        self.area
+            .iter()
+            .try_fold(0usize, |sum, c| sum.checked_add(c.size()?))
+            .ok_or(Error::solve("More cubes are on than fit in a usize"))
+    }
+}
+
+#[aoc(day22, part2)]
+pub fn solve_part2(input: &Vec<Cuboid>) -> Result<usize, Error> {
+    let mut area = CuboidMerge::new();
+
+    for c in input {
+        area.merge(c);
+    }
+
+    area.count()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Cuboid>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen::{self, Rng};
+
+    // The generated cuboids all lie within the region of part 1, so
+    // counting cubes one by one has to agree with splitting cuboids.
+    #[test]
+    fn splitting_matches_counting() -> Result<(), Error> {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let input = input_generator(&gen::y2021::day22(&mut rng, 1 + seed as usize % 5))?;
+
+            assert_eq!(solve_part1(&input)? as u64, solve_part2(&input)? as u64, "seed {}", seed);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn large_reactors() -> Result<(), Error> {
+        assert!(input_generator("on x=-4294967296..4294967296,y=-4294967296..4294967296,z=0..0").is_err());
+
+        let input = input_generator("on x=-999999..999999,y=-999999..999999,z=-999999..999999\noff x=0..0,y=0..0,z=0..0")?;
+        Ok(assert_eq!(7_999_988_000_005_999_998, solve_part2(&input)?))
+    }
+}
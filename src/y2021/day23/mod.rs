@@ -0,0 +1,130 @@
+use crate::utils::Error;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Amphipod {
+    A,
+    B,
+    C,
+    D,
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rooms {
+    // This is how the hallway and the rooms
+    // are stored in this structure:
+    //
+    // #############
+    // #01.2.3.4.56#  <- hallway
+    // ###0#1#2#3###  <- rooms
+    //   #4#5#6#7#    <- rooms
+    //   #########
+    hallway: [char; 7],
+    rooms: [char; 8],
+}
+
+// Columns of the diagram that are part of the structure above.
+const HALLWAY: [usize; 7] = [1, 2, 4, 6, 8, 10, 11];
+const ROOMS: [usize; 4] = [3, 5, 7, 9];
+
+#[aoc_generator(day23)]
+pub fn input_generator(input: &str) -> Result<Rooms, Error> {
+    let lines = input.lines().collect::<Vec<_>>();
+    if lines.len() < 4 {
+        return Err(Error::new("Expected a hallway and two rows of rooms"));
+    }
+
+    let cell = |y: usize, x: usize, allowed: &str| {
+        let line = lines[y];
+        match line.get(x..x + 1) {
+            Some(c) if allowed.contains(c) => Ok(c.chars().next().unwrap_or('.')),
+            Some(c) => Err(Error::token(line, c, "Expected an amphipod").at_line(y + 1)),
+            None => Err(Error::new("The diagram is too short").at_line(y + 1)),
+        }
+    };
+
+    let mut hallway = ['.'; 7];
+    for (i, x) in HALLWAY.iter().enumerate() {
+        hallway[i] = cell(1, *x, ".ABCD")?;
+    }
+
+    let mut rooms = ['.'; 8];
+    for (i, x) in ROOMS.iter().enumerate() {
+        rooms[i] = cell(2, *x, ".ABCD")?;
+        rooms[i + 4] = cell(3, *x, ".ABCD")?;
+    }
+
+    for a in ['A', 'B', 'C', 'D'] {
+        if hallway.iter().chain(rooms.iter()).filter(|c| **c == a).count() != 2 {
+            return Err(Error::new(&format!("Expected two amphipods of type {}", a)));
+        }
+    }
+
+    Ok(Rooms { rooms, hallway })
+}
+
+impl Rooms {
+    #[allow(dead_code)]
+    fn possible_movements(&self) -> Vec<(usize, usize)> {
+        // get the top row out of their rooms, that are not in their
+        // rooms yet or still have visitors.
+
+        vec![]
+    }
+}
+
+#[aoc(day23, part1)]
+pub fn solve_part1(input: &Rooms) -> Result<i32, Error> {
+    let _rooms = input.clone();
+    Ok(0)
+}
+
+#[aoc(day23, part2)]
+pub fn solve_part2(_input: &Rooms) -> Result<i32, Error> {
+    Ok(0)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Rooms;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::Error;
+
+    fn sample() -> &'static str {
+        include_str!("../../../fixtures/2021/day23/sample.txt")
+    }
+
+    #[test]
+    fn parse_sample() -> Result<(), Error> {
+        let data = input_generator(sample())?;
+
+        assert_eq!(['B', 'C', 'B', 'D', 'A', 'D', 'C', 'A'], data.rooms);
+        assert_eq!(['.'; 7], data.hallway);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = input_generator(&sample().replace("#A#D", "#A#E")).unwrap_err();
+        assert_eq!("line 4, column 6: Expected an amphipod `E`", error.to_string());
+        assert!(input_generator(&sample().replace("#A#D", "#A#A")).is_err());
+    }
+}
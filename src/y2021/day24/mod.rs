@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use crate::utils::{parse_token, Error};
+use crate::solution::{Answer, Solution};
+
+// The program is made of one block per digit of the model number that
+// only differ in three constants:
+//
+// w = IN
+// x = z % 26 + check
+// z /= div
+// if x != w {
+//   z = z * 26 + w + offset
+// }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Block {
+    div: i64,
+    check: i64,
+    offset: i64,
+}
+
+// One block for each digit of a model number.
+pub const DIGITS: usize = 14;
+
+pub const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z", "add x", "eql x w", "eql x 0", "mul y 0",
+    "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y", "mul y x", "add z y",
+];
+
+// Checks that the line `i` of a block is the expected instruction and
+// returns its constant, if it has one.
+fn instruction(line: &str, i: usize) -> Result<Option<i64>, Error> {
+    let expected = BLOCK[i];
+
+    if line == expected {
+        return Ok(None);
+    }
+
+    match line.strip_prefix(expected).and_then(|l| l.strip_prefix(' ')) {
+        Some(constant) if expected.len() == 5 => Ok(Some(parse_token(line, constant)?)),
+        _ => Err(Error::token(line, line, &format!("Expected {}", expected))),
+    }
+}
+
+#[aoc_generator(day24)]
+pub fn input_generator(input: &str) -> Result<Vec<Block>, Error> {
+    let lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if lines.len() != DIGITS * BLOCK.len() {
+        return Err(Error::new(&format!("Expected {} blocks of {} instructions", DIGITS, BLOCK.len())));
+    }
+
+    lines
+        .chunks(BLOCK.len())
+        .map(|block| {
+            let constant = |i: usize| {
+                let (n, line) = block[i];
+                instruction(line.trim(), i)
+                    .and_then(|c| c.ok_or_else(|| Error::token(line, line, "Expected a constant")))
+                    .map_err(|e| e.at_line(n + 1))
+            };
+
+            for (i, (n, line)) in block.iter().enumerate() {
+                instruction(line.trim(), i).map_err(|e| e.at_line(n + 1))?;
+            }
+
+            let div = constant(4)?;
+            if div != 1 && div != 26 {
+                let (n, line) = block[4];
+                return Err(Error::token(line, line, "Expected div z 1 or div z 26").at_line(n + 1));
+            }
+
+            Ok(Block { div, check: constant(5)?, offset: constant(15)? })
+        })
+        .collect()
+}
+
+// None when z no longer fits, no model number goes that way.
+fn cell(w: i64, z: i64, block: &Block) -> Option<i64> {
+    let x = block.check.checked_add(z % 26)?;
+    let z = z / block.div;
+
+    if x != w {
+        return z.checked_mul(26)?.checked_add(w)?.checked_add(block.offset);
+    }
+
+    Some(z)
+}
+
+fn monad(blocks: &[Block], z: i64, i: usize, memo: &mut HashMap<(i64, usize), Vec<Vec<i64>>>) -> Vec<Vec<i64>> {
+    if i == blocks.len() {
+        if z == 0 {
+            return vec![vec![]];
+        } else {
+            return vec![];
+        }
+    }
+
+    if let Some(models) = memo.get(&(z, i)) {
+        return models.clone();
+    }
+
+    // Only the blocks dividing by 26 make z smaller, there is no way back
+    // to 0 from above 26 to the power of how many are left.
+    let shrinks = blocks[i..].iter().filter(|b| b.div == 26).count() as u32;
+    if 26i64.checked_pow(shrinks).is_some_and(|max| z >= max) {
+        return vec![];
+    }
+
+    let next = blocks[i].check.checked_add(z % 26).unwrap_or_default();
+    let next_digits = if 0 < next && next < 10 {
+        vec![next]
+    } else {
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+    };
+
+    let mut model_nums = vec![];
+    for d in next_digits.into_iter() {
+        let z_next = match cell(d, z, &blocks[i]) {
+            Some(z) => z,
+            None => continue,
+        };
+        let mut models = monad(blocks, z_next, i + 1, memo);
+        for model in &mut models {
+            model.push(d);
+        }
+        model_nums.append(&mut models);
+    }
+
+    memo.insert((z, i), model_nums.clone());
+    model_nums
+}
+
+fn model_numbers(blocks: &[Block]) -> Vec<i64> {
+    monad(blocks, 0, 0, &mut HashMap::new())
+        .into_iter()
+        .map(|v| to_num(v.into_iter().rev().collect::<Vec<_>>()))
+        .collect()
+}
+
+fn to_num(v: Vec<i64>) -> i64 {
+    v.iter().fold(0, |acc, d| acc * 10 + d)
+}
+
+// tried: 51131616112781
+// tried: 59998494939729
+#[aoc(day24, part1)]
+pub fn solve_part1(input: &[Block]) -> Result<i64, Error> {
+    model_numbers(input).into_iter().max().ok_or(Error::solve("There is no valid model number"))
+}
+
+#[aoc(day24, part2)]
+pub fn solve_part2(input: &[Block]) -> Result<i64, Error> {
+    model_numbers(input).into_iter().min().ok_or(Error::solve("There is no valid model number"))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Block>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::Error;
+
+    // Fills the program up to 14 blocks with pairs that only take 1 and 9.
+    fn program(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .chain([(1, 11, 8), (26, 0, 0)].iter().cycle().take(DIGITS - blocks.len()))
+            .flat_map(|(div, check, offset)| {
+                BLOCK.iter().enumerate().map(move |(i, instruction)| match i {
+                    4 => format!("{} {}\n", instruction, div),
+                    5 => format!("{} {}\n", instruction, check),
+                    15 => format!("{} {}\n", instruction, offset),
+                    _ => format!("{}\n", instruction),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_blocks() -> Result<(), Error> {
+        let data = input_generator(&program(&[(1, 11, 8), (26, -8, 13)]))?;
+
+        assert_eq!(vec![Block { div: 1, check: 11, offset: 8 }, Block { div: 26, check: -8, offset: 13 }], data[..2].to_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = input_generator(&program(&[(1, 11, 8)]).replacen("add x z", "add x y", 1)).unwrap_err();
+        assert_eq!("line 3, column 1: Expected add x z `add x y`", error.to_string());
+        assert_eq!("line 5, column 1: Expected div z 1 or div z 26 `div z 0`", input_generator(&program(&[(0, 11, 8)])).unwrap_err().to_string());
+        assert!(input_generator(&program(&[]).repeat(2)).is_err());
+        assert!(input_generator("inp w\n").is_err());
+    }
+
+    #[test]
+    fn largest_and_smallest_model() -> Result<(), Error> {
+        // Accepts any pair of digits with w2 = w1 + 3.
+        let data = input_generator(&program(&[(1, 11, 3), (26, 0, 7)]))?;
+
+        assert_eq!(69_191_919_191_919, solve_part1(&data)?);
+        Ok(assert_eq!(14_191_919_191_919, solve_part2(&data)?))
+    }
+}
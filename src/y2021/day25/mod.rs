@@ -0,0 +1,154 @@
+use std::fmt;
+use crate::budget::Budget;
+use crate::render::{Picture, Render};
+use crate::utils::{Error, Grid};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day25)]
+pub fn input_generator(input: &str) -> Result<Grid<char>, Error> {
+    let floor = Grid::parse(input, |c| match c {
+        '>' | 'v' | '.' => Some(c),
+        _ => None,
+    })?;
+    if floor.width() == 0 {
+        return Err(Error::new("The seafloor is empty"));
+    }
+
+    Ok(floor)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seafloor {
+    floor: Grid<char>,
+}
+
+impl Seafloor {
+    pub fn new(floor: Grid<char>) -> Self {
+        Self { floor }
+    }
+
+    pub fn move_east(&self) -> Self {
+        let mut new_floor = Grid::new(self.floor.width(), self.floor.height(), '.');
+
+        for ((x, y), c) in self.floor.iter() {
+            let next = (x as isize + 1, y as isize);
+
+            if *c == '>' && self.floor.get_wrapping(next.0, next.1) == &'.' {
+                new_floor[((x + 1) % self.floor.width(), y)] = '>';
+            } else if *c != '.' {
+                new_floor[(x, y)] = *c;
+            }
+        }
+
+        Self { floor: new_floor }
+    }
+
+    pub fn move_south(&self, east: Grid<char>) -> Self {
+        let mut new_floor = Grid::new(east.width(), east.height(), '.');
+
+        for ((x, y), c) in east.iter() {
+            let next = (x as isize, y as isize + 1);
+
+            if *c == 'v' && east.get_wrapping(next.0, next.1) == &'.' {
+                new_floor[(x, (y + 1) % east.height())] = 'v';
+            } else if *c != '.' {
+                new_floor[(x, y)] = *c;
+            }
+        }
+
+        Self { floor: new_floor }
+    }
+
+    pub fn tick(&self) -> Self {
+        let east = self.move_east();
+        self.move_south(east.floor)
+    }
+
+}
+
+impl fmt::Display for Seafloor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.floor)
+    }
+}
+
+impl Render for Seafloor {
+    fn render(&self) -> Picture {
+        Picture::colour(&self.floor, |c| match c {
+            '>' => [230, 120, 40],
+            'v' => [60, 140, 230],
+            _ => [10, 20, 50],
+        })
+    }
+}
+
+// The herds before they start moving and after each of the next `steps`
+// steps, or until they stop.
+pub fn frames(input: &Grid<char>, steps: usize) -> Result<Vec<Picture>, Error> {
+    let mut current = Seafloor::new(input.clone());
+    let mut frames = vec![current.render()];
+
+    for _ in 0..steps {
+        let next = current.tick();
+        if next == current {
+            break;
+        }
+
+        current = next;
+        frames.push(current.render());
+    }
+
+    Ok(frames)
+}
+
+const STEPS: u64 = 10000;
+
+#[aoc(day25, part1)]
+pub fn solve_part1(input: &Grid<char>) -> Result<usize, Error> {
+    solve_part1_within(input, &mut Budget::steps(STEPS))
+}
+
+// Some herds keep going around in circles forever, every move of them is
+// a step.
+pub fn solve_part1_within(input: &Grid<char>, budget: &mut Budget) -> Result<usize, Error> {
+    let mut current = Seafloor::new(input.clone());
+    let mut stable = 0;
+
+    loop {
+        budget.step(|| format!("The sea cucumbers are still moving after {} steps", stable))?;
+        stable += 1;
+
+        let next = current.tick();
+        if next == current {
+            return Ok(stable);
+        }
+        current = next;
+    }
+}
+
+#[aoc(day25, part2)]
+pub fn solve_part2(_input: &Grid<char>) -> Result<usize, Error> {
+    Ok(0)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part1_within(input: &Self::Input, budget: &mut Budget) -> Result<Answer, Error> {
+        Ok(solve_part1_within(input, budget)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part2(input)?.into())
+    }
+}
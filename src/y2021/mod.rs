@@ -0,0 +1,55 @@
+use crate::solution::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub static DAYS: &[Day] = &[
+    Day { year: 2021, day: 1, solution: &day01::Day01 },
+    Day { year: 2021, day: 2, solution: &day02::Day02 },
+    Day { year: 2021, day: 3, solution: &day03::Day03 },
+    Day { year: 2021, day: 4, solution: &day04::Day04 },
+    Day { year: 2021, day: 5, solution: &day05::Day05 },
+    Day { year: 2021, day: 6, solution: &day06::Day06 },
+    Day { year: 2021, day: 7, solution: &day07::Day07 },
+    Day { year: 2021, day: 8, solution: &day08::Day08 },
+    Day { year: 2021, day: 9, solution: &day09::Day09 },
+    Day { year: 2021, day: 10, solution: &day10::Day10 },
+    Day { year: 2021, day: 11, solution: &day11::Day11 },
+    Day { year: 2021, day: 12, solution: &day12::Day12 },
+    Day { year: 2021, day: 13, solution: &day13::Day13 },
+    Day { year: 2021, day: 14, solution: &day14::Day14 },
+    Day { year: 2021, day: 15, solution: &day15::Day15 },
+    Day { year: 2021, day: 16, solution: &day16::Day16 },
+    Day { year: 2021, day: 17, solution: &day17::Day17 },
+    Day { year: 2021, day: 18, solution: &day18::Day18 },
+    Day { year: 2021, day: 19, solution: &day19::Day19 },
+    Day { year: 2021, day: 20, solution: &day20::Day20 },
+    Day { year: 2021, day: 21, solution: &day21::Day21 },
+    Day { year: 2021, day: 22, solution: &day22::Day22 },
+    Day { year: 2021, day: 23, solution: &day23::Day23 },
+    Day { year: 2021, day: 24, solution: &day24::Day24 },
+    Day { year: 2021, day: 25, solution: &day25::Day25 },
+];